@@ -20,7 +20,7 @@ fn main() {
 
   for string in fretboard.strings() {
     for fret in string.frets().clone().iter_mut() {
-      println!("{} {}", fret.pitch(), fret.frequency());
+      println!("{} {}", fret.pitch_class(), fret.frequency());
     }
   }
 }
@@ -0,0 +1,125 @@
+// This file is part of Guitar <https://github.com/Fuwn/guitar>.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+//
+// Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::fmt;
+
+use crate::unit::Semitone;
+
+/// An alteration applied to a [`Letter`](crate::Letter), from a double flat
+/// to a double sharp.
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::Accidental;
+///
+/// assert_eq!(Accidental::from_symbol("bb"), Some(Accidental::DoubleFlat));
+/// assert_eq!(Accidental::Sharp.semitones(), 1);
+/// assert_eq!(format!("{:#}", Accidental::Flat), "♭");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Accidental {
+  DoubleFlat,
+  Flat,
+  Natural,
+  Sharp,
+  DoubleSharp,
+}
+
+impl Accidental {
+  /// Returns the [`Accidental`] which alters a pitch by a number of
+  /// [`Semitone`]s, if one exists.
+  #[must_use]
+  pub const fn from_semitones(semitones: Semitone) -> Option<Self> {
+    match semitones {
+      -2 => Some(Self::DoubleFlat),
+      -1 => Some(Self::Flat),
+      0 => Some(Self::Natural),
+      1 => Some(Self::Sharp),
+      2 => Some(Self::DoubleSharp),
+      _ => None,
+    }
+  }
+
+  /// Returns the [`Accidental`] represented by an ASCII or Unicode symbol;
+  /// "x" is accepted as a double sharp.
+  #[must_use]
+  pub fn from_symbol(symbol: &str) -> Option<Self> {
+    match symbol {
+      "bb" | "♭♭" | "𝄫" => Some(Self::DoubleFlat),
+      "b" | "♭" => Some(Self::Flat),
+      "" | "♮" => Some(Self::Natural),
+      "#" | "♯" => Some(Self::Sharp),
+      "##" | "x" | "♯♯" | "𝄪" => Some(Self::DoubleSharp),
+      _ => None,
+    }
+  }
+
+  /// Returns whether a character can appear in the symbol of an
+  /// [`Accidental`].
+  #[must_use]
+  pub const fn is_symbol(character: char) -> bool {
+    matches!(character, 'b' | '#' | 'x' | '♭' | '♮' | '♯' | '𝄪' | '𝄫')
+  }
+
+  /// Returns the number of [`Semitone`]s that the [`Accidental`] alters a
+  /// pitch by.
+  #[must_use]
+  pub const fn semitones(self) -> Semitone {
+    match self {
+      Self::DoubleFlat => -2,
+      Self::Flat => -1,
+      Self::Natural => 0,
+      Self::Sharp => 1,
+      Self::DoubleSharp => 2,
+    }
+  }
+
+  /// Returns the ASCII symbol of the [`Accidental`].
+  #[must_use]
+  pub const fn symbol(self) -> &'static str {
+    match self {
+      Self::DoubleFlat => "bb",
+      Self::Flat => "b",
+      Self::Natural => "",
+      Self::Sharp => "#",
+      Self::DoubleSharp => "##",
+    }
+  }
+
+  /// Returns the Unicode symbol of the [`Accidental`].
+  #[must_use]
+  pub const fn unicode_symbol(self) -> &'static str {
+    match self {
+      Self::DoubleFlat => "𝄫",
+      Self::Flat => "♭",
+      Self::Natural => "",
+      Self::Sharp => "♯",
+      Self::DoubleSharp => "𝄪",
+    }
+  }
+}
+
+impl fmt::Display for Accidental {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if f.alternate() {
+      write!(f, "{}", self.unicode_symbol())
+    } else {
+      write!(f, "{}", self.symbol())
+    }
+  }
+}
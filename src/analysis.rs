@@ -0,0 +1,396 @@
+// This file is part of Guitar <https://github.com/Fuwn/guitar>.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+//
+// Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
+// SPDX-License-Identifier: GPL-3.0-only
+
+#![allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+
+use std::fmt;
+
+use crate::{
+  chord::Chord, interval::Interval, key::Key, pitch::Pitch,
+  pitch_class::PitchClass, scale::ScaleKind, unit::Semitone,
+};
+
+const NUMERALS: [&str; 7] = ["I", "II", "III", "IV", "V", "VI", "VII"];
+
+/// The harmonic function of a chord within a [`Key`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HarmonicFunction {
+  Tonic,
+  Predominant,
+  Dominant,
+}
+
+/// How a chord relates to the [`Key`] it was analysed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NumeralKind {
+  /// Built only from the key's own scale; in minor keys, the raised leading
+  /// tone counts as diatonic.
+  Diatonic,
+  /// A dominant or leading-tone chord of a diatonic chord other than the
+  /// tonic, e.g., V7/V.
+  Secondary,
+  /// Borrowed from the parallel key, e.g., bVII or iv in a major key.
+  Borrowed,
+  /// A major triad on the lowered second degree.
+  Neapolitan,
+  /// An Italian, French, or German augmented sixth chord.
+  AugmentedSixth,
+  /// Any other chromatic chord.
+  Chromatic,
+}
+
+/// The Roman numeral analysis of a [`Chord`] within a [`Key`].
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::{analysis, Chord, Key, PitchClass};
+///
+/// let key = Key::major(PitchClass::from_name("C").unwrap());
+/// let numerals = ["C", "Dm7", "D7/F#", "G7", "Bb", "C"]
+///   .map(|symbol| symbol.parse::<Chord>().unwrap());
+/// let labels = analysis::analyze_progression(key, &numerals)
+///   .iter()
+///   .map(ToString::to_string)
+///   .collect::<Vec<_>>();
+///
+/// assert_eq!(labels, ["I", "ii7", "V65/V", "V7", "bVII", "I"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RomanNumeral {
+  label: String,
+  degree: usize,
+  function: Option<HarmonicFunction>,
+  kind: NumeralKind,
+}
+
+impl RomanNumeral {
+  /// Returns the label of the [`RomanNumeral`], e.g., "V7/V" or "It+6".
+  #[must_use]
+  pub fn label(&self) -> &str { &self.label }
+
+  /// Returns the scale degree of the chord's root, from 1 to 7.
+  #[must_use]
+  pub const fn degree(&self) -> usize { self.degree }
+
+  /// Returns the [`HarmonicFunction`] of the chord, if it has a clear one.
+  #[must_use]
+  pub const fn function(&self) -> Option<HarmonicFunction> { self.function }
+
+  /// Returns how the chord relates to the [`Key`].
+  #[must_use]
+  pub const fn kind(&self) -> NumeralKind { self.kind }
+
+  /// Returns whether the chord is a secondary dominant or leading-tone chord.
+  #[must_use]
+  pub fn is_secondary(&self) -> bool { self.kind == NumeralKind::Secondary }
+
+  /// Returns whether the chord is borrowed from the parallel key.
+  #[must_use]
+  pub fn is_borrowed(&self) -> bool { self.kind == NumeralKind::Borrowed }
+}
+
+impl fmt::Display for RomanNumeral {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.label)
+  }
+}
+
+/// Analyses every [`Chord`] of a progression within a [`Key`]; see
+/// [`analyze`].
+#[must_use]
+pub fn analyze_progression(key: Key, chords: &[Chord]) -> Vec<RomanNumeral> {
+  chords.iter().map(|chord| analyze(key, chord)).collect()
+}
+
+/// Analyses a [`Chord`] within a [`Key`], labelling it with a Roman numeral,
+/// a [`HarmonicFunction`], and a [`NumeralKind`].
+///
+/// Inversions of slash chords are written with figured-bass figures (e.g.,
+/// "V65").
+#[must_use]
+pub fn analyze(key: Key, chord: &Chord) -> RomanNumeral {
+  let classes = chord_classes(key, chord);
+  let degree = degree_of(key, chord.root());
+  let function = degree_function(degree);
+
+  if let Some(label) = augmented_sixth(key, chord, &classes) {
+    return RomanNumeral {
+      label: label.to_string(),
+      degree: 6,
+      function: Some(HarmonicFunction::Predominant),
+      kind: NumeralKind::AugmentedSixth,
+    };
+  }
+
+  if degree == 2
+    && root_offset(key, chord.root()) == -1
+    && triad(chord) == Triad::Major
+  {
+    return RomanNumeral {
+      label: format!("N{}", figure(chord)),
+      degree,
+      function: Some(HarmonicFunction::Predominant),
+      kind: NumeralKind::Neapolitan,
+    };
+  }
+
+  let diatonic = is_diatonic(key, &classes);
+
+  if !diatonic {
+    if let Some(secondary) = secondary(key, chord) {
+      return secondary;
+    }
+  }
+
+  let borrowed = !diatonic && is_diatonic(key.parallel(), &classes);
+
+  RomanNumeral {
+    label: numeral(key, chord),
+    degree,
+    function: (diatonic || borrowed).then_some(function),
+    kind: if diatonic {
+      NumeralKind::Diatonic
+    } else if borrowed {
+      NumeralKind::Borrowed
+    } else {
+      NumeralKind::Chromatic
+    },
+  }
+}
+
+/// Returns the [`Semitone`]s of a [`Chord`]'s tones above the tonic.
+fn chord_classes(key: Key, chord: &Chord) -> Vec<Semitone> {
+  let mut classes = chord
+    .pitch_classes()
+    .iter()
+    .map(|class| (class.semitones() - key.tonic().semitones()).rem_euclid(12))
+    .collect::<Vec<_>>();
+
+  classes.sort_unstable();
+  classes.dedup();
+
+  classes
+}
+
+fn is_diatonic(key: Key, classes: &[Semitone]) -> bool {
+  let mut scale = key
+    .scale(4)
+    .intervals()
+    .iter()
+    .map(|interval| interval.semitones())
+    .collect::<Vec<_>>();
+
+  if key.mode() == ScaleKind::MINOR {
+    scale.push(11);
+  }
+
+  classes.iter().all(|class| scale.contains(class))
+}
+
+/// Returns the scale degree of a [`PitchClass`] by letter, from 1 to 7.
+const fn degree_of(key: Key, class: PitchClass) -> usize {
+  (class.letter().index() + 7 - key.tonic().letter().index()) % 7 + 1
+}
+
+/// Returns how many [`Semitone`]s a root is above or below the scale degree
+/// sharing its letter.
+fn root_offset(key: Key, root: PitchClass) -> Semitone {
+  let degree = degree_of(key, root);
+  let expected = key.scale(4).intervals()[degree - 1].semitones();
+  let actual = (root.semitones() - key.tonic().semitones()).rem_euclid(12);
+
+  (actual - expected + 6).rem_euclid(12) - 6
+}
+
+const fn degree_function(degree: usize) -> HarmonicFunction {
+  match degree {
+    2 | 4 => HarmonicFunction::Predominant,
+    5 | 7 => HarmonicFunction::Dominant,
+    _ => HarmonicFunction::Tonic,
+  }
+}
+
+fn interval_of(chord: &Chord, number: i64) -> Option<Interval> {
+  chord
+    .intervals()
+    .iter()
+    .copied()
+    .find(|interval| interval.number() == number)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Triad {
+  Major,
+  Minor,
+  Diminished,
+  Augmented,
+  Other,
+}
+
+/// Returns the quality of the triad at the bottom of a [`Chord`].
+fn triad(chord: &Chord) -> Triad {
+  match (interval_of(chord, 3), interval_of(chord, 5)) {
+    (Some(Interval::MAJOR_THIRD), Some(Interval::AUGMENTED_FIFTH)) =>
+      Triad::Augmented,
+    (Some(Interval::MAJOR_THIRD), _) => Triad::Major,
+    (Some(Interval::MINOR_THIRD), Some(Interval::DIMINISHED_FIFTH)) =>
+      Triad::Diminished,
+    (Some(Interval::MINOR_THIRD), _) => Triad::Minor,
+    _ => Triad::Other,
+  }
+}
+
+/// Returns the figured-bass figure of a [`Chord`]'s inversion, including the
+/// seventh of a root-position seventh chord.
+fn figure(chord: &Chord) -> &'static str {
+  let seventh = interval_of(chord, 7).is_some();
+  let bass = chord.bass().and_then(|bass| {
+    chord
+      .intervals()
+      .iter()
+      .zip(chord.pitch_classes())
+      .find(|(_, class)| class.is_enharmonic(bass))
+      .map(|(interval, _)| interval.number())
+  });
+
+  match (bass, seventh) {
+    (Some(3), false) => "6",
+    (Some(5), false) => "64",
+    (Some(3), true) => "65",
+    (Some(5), true) => "43",
+    (Some(7), true) => "42",
+    (_, true) => "7",
+    _ => "",
+  }
+}
+
+/// Writes the numeral of a [`Chord`] on a degree, cased and marked for its
+/// quality, without an accidental or a figure.
+fn bare_numeral(chord: &Chord, degree: usize) -> String {
+  let numeral = NUMERALS[degree - 1];
+  let seventh = interval_of(chord, 7);
+
+  match triad(chord) {
+    Triad::Minor => numeral.to_lowercase(),
+    Triad::Diminished if seventh == Some(Interval::MINOR_SEVENTH) =>
+      format!("{}ø", numeral.to_lowercase()),
+    Triad::Diminished => format!("{}°", numeral.to_lowercase()),
+    Triad::Augmented => format!("{numeral}+"),
+    Triad::Major if seventh == Some(Interval::MAJOR_SEVENTH) =>
+      format!("{numeral}M"),
+    _ => numeral.to_string(),
+  }
+}
+
+fn numeral(key: Key, chord: &Chord) -> String {
+  let degree = degree_of(key, chord.root());
+  let offset = root_offset(key, chord.root());
+  let raised_leading_tone =
+    key.mode() == ScaleKind::MINOR && degree == 7 && offset == 1;
+  let accidental = match offset {
+    _ if raised_leading_tone => "",
+    ..=-2 => "bb",
+    -1 => "b",
+    0 => "",
+    1 => "#",
+    _ => "##",
+  };
+
+  format!(
+    "{accidental}{}{}",
+    bare_numeral(chord, degree),
+    figure(chord)
+  )
+}
+
+/// Labels an augmented sixth chord by its tones above the tonic, if it spells
+/// the augmented sixth between a lowered sixth degree and a raised fourth
+/// (e.g., Ab and F# in C, but not Ab and Gb).
+fn augmented_sixth(
+  key: Key,
+  chord: &Chord,
+  classes: &[Semitone],
+) -> Option<&'static str> {
+  let spells = |degree, semitones| {
+    chord.pitch_classes().iter().any(|&class| {
+      degree_of(key, class) == degree
+        && (class.semitones() - key.tonic().semitones()).rem_euclid(12)
+          == semitones
+    })
+  };
+
+  if !spells(6, 8) || !spells(4, 6) {
+    return None;
+  }
+
+  match classes {
+    [0, 6, 8] => Some("It+6"),
+    [0, 2, 6, 8] => Some("Fr+6"),
+    [0, 3, 6, 8] => Some("Ger+6"),
+    _ => None,
+  }
+}
+
+/// Labels a secondary dominant or leading-tone chord, if the [`Chord`] is
+/// one.
+fn secondary(key: Key, chord: &Chord) -> Option<RomanNumeral> {
+  let root = Pitch::new_from_pitch_class(chord.root(), 4);
+  let (target, numeral) = match triad(chord) {
+    Triad::Major
+      if interval_of(chord, 7).is_none_or(|i| i == Interval::MINOR_SEVENTH) =>
+      ((&root - Interval::PERFECT_FIFTH).pitch_class(), 5),
+    Triad::Diminished => ((&root + Interval::MINOR_SECOND).pitch_class(), 7),
+    _ => return None,
+  };
+  let target_degree = degree_of(key, target);
+
+  if target_degree == 1 || root_offset(key, target) != 0 {
+    return None;
+  }
+
+  let scale = key.scale(4);
+  let target_triad = [target_degree, target_degree + 2, target_degree + 4]
+    .map(|degree| scale.degree(degree).map(|pitch| pitch.pitch_class()));
+  let target_chord = match target_triad {
+    [Some(root), Some(third), Some(fifth)] => format!(
+      "{root}{}",
+      match (
+        (third.semitones() - root.semitones()).rem_euclid(12),
+        (fifth.semitones() - root.semitones()).rem_euclid(12),
+      ) {
+        (4, 7) => "",
+        (3, 7) => "m",
+        _ => return None,
+      }
+    ),
+    _ => return None,
+  };
+  let target_chord = target_chord.parse::<Chord>().ok()?;
+
+  Some(RomanNumeral {
+    label: format!(
+      "{}{}/{}",
+      bare_numeral(chord, numeral),
+      figure(chord),
+      bare_numeral(&target_chord, target_degree)
+    ),
+    degree: degree_of(key, chord.root()),
+    function: Some(HarmonicFunction::Dominant),
+    kind: NumeralKind::Secondary,
+  })
+}
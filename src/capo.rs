@@ -0,0 +1,99 @@
+// This file is part of Guitar <https://github.com/Fuwn/guitar>.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+//
+// Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
+// SPDX-License-Identifier: GPL-3.0-only
+
+/// A capo clamped across a fret, which raises the strings it covers so that
+/// the fret becomes their new nut.
+///
+/// Strings are indexed in the same order as
+/// [`Fretboard::strings`](crate::Fretboard::strings).
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::Capo;
+///
+/// let full = Capo::new(2);
+/// let drop_d = Capo::new_partial(2, &[1, 2, 3, 4, 5]);
+///
+/// assert!(full.covers(0));
+/// assert!(!drop_d.covers(0));
+/// assert!(drop_d.covers(5));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Capo {
+  fret: usize,
+  strings: Option<Vec<usize>>,
+}
+
+impl Capo {
+  /// Creates a new [`Capo`] which covers every string at a fret.
+  #[must_use]
+  pub const fn new(fret: usize) -> Self {
+    Self {
+      fret,
+      strings: None,
+    }
+  }
+
+  /// Creates a new partial [`Capo`] which only covers some strings at a fret
+  /// (e.g., a drop-D capo which leaves the lowest string open).
+  #[must_use]
+  pub fn new_partial(fret: usize, strings: &[usize]) -> Self {
+    let mut strings = strings.to_vec();
+
+    strings.sort_unstable();
+    strings.dedup();
+
+    Self {
+      fret,
+      strings: Some(strings),
+    }
+  }
+
+  /// Returns the fret which the [`Capo`] is clamped across.
+  #[must_use]
+  pub const fn fret(&self) -> usize { self.fret }
+
+  /// Returns the strings which the [`Capo`] covers, or [`None`] if it covers
+  /// every string.
+  #[must_use]
+  pub fn strings(&self) -> Option<&[usize]> { self.strings.as_deref() }
+
+  /// Returns whether the [`Capo`] is partial.
+  #[must_use]
+  pub const fn is_partial(&self) -> bool { self.strings.is_some() }
+
+  /// Returns whether the [`Capo`] covers a string.
+  #[must_use]
+  pub fn covers(&self, string: usize) -> bool {
+    self
+      .strings
+      .as_ref()
+      .is_none_or(|strings| strings.binary_search(&string).is_ok())
+  }
+
+  /// Returns the fret which a string sounds at when played open, which is the
+  /// [`Capo`]'s fret if it covers the string and 0 otherwise.
+  #[must_use]
+  pub fn nut(&self, string: usize) -> usize {
+    if self.covers(string) {
+      self.fret
+    } else {
+      0
+    }
+  }
+}
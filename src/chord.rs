@@ -0,0 +1,657 @@
+// This file is part of Guitar <https://github.com/Fuwn/guitar>.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+//
+// Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::{fmt, str::FromStr};
+
+use crate::{
+  error::ChordError,
+  interval::{Interval, Quality},
+  pitch::Pitch,
+  pitch_class::PitchClass,
+  transpose::{Transpose, Transposition},
+  unit::{IntervalNumber, Octave, Semitone},
+};
+
+/// The role which a [`ChordTone`] plays within its [`Chord`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ChordFunction {
+  Root,
+  Second,
+  Third,
+  Fourth,
+  Fifth,
+  Sixth,
+  Seventh,
+  Ninth,
+  Eleventh,
+  Thirteenth,
+}
+
+impl ChordFunction {
+  /// Returns the [`ChordFunction`] of a tone a given [`Interval`] above the
+  /// root.
+  #[must_use]
+  pub const fn from_interval(interval: Interval) -> Self {
+    match interval.number() {
+      9 => Self::Ninth,
+      11 => Self::Eleventh,
+      13 => Self::Thirteenth,
+      number => match (number - 1) % 7 {
+        1 => Self::Second,
+        2 => Self::Third,
+        3 => Self::Fourth,
+        4 => Self::Fifth,
+        5 => Self::Sixth,
+        6 => Self::Seventh,
+        _ => Self::Root,
+      },
+    }
+  }
+
+  /// Returns whether the [`ChordFunction`] is a tension above the seventh.
+  #[must_use]
+  pub const fn is_extension(self) -> bool {
+    matches!(self, Self::Ninth | Self::Eleventh | Self::Thirteenth)
+  }
+}
+
+/// A spelled tone of a [`Chord`], together with its [`Interval`] above the
+/// root.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct ChordTone {
+  pitch: Pitch,
+  interval: Interval,
+}
+
+impl ChordTone {
+  /// Returns the [`Pitch`] of the [`ChordTone`].
+  #[must_use]
+  pub const fn pitch(&self) -> &Pitch { &self.pitch }
+
+  /// Returns the [`Interval`] of the [`ChordTone`] above the root.
+  #[must_use]
+  pub const fn interval(&self) -> Interval { self.interval }
+
+  /// Returns the [`ChordFunction`] of the [`ChordTone`].
+  #[must_use]
+  pub const fn function(&self) -> ChordFunction {
+    ChordFunction::from_interval(self.interval)
+  }
+}
+
+/// The chord symbol suffixes which [`Chord::identify`] tries, from the most to
+/// the least common.
+const TEMPLATES: [&str; 36] = [
+  "", "m", "7", "maj7", "m7", "5", "sus4", "sus2", "°", "+", "6", "m6", "ø7",
+  "°7", "7sus4", "add9", "madd9", "9", "maj9", "m9", "mM7", "+7", "6/9", "7b9",
+  "7#9", "7b5", "7#5", "maj7#11", "7#11", "11", "m11", "13", "maj13", "m13",
+  "7b13", "+maj7",
+];
+
+/// A candidate name for a set of pitches, as returned by [`Chord::identify`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChordMatch {
+  chord: Chord,
+  bass_function: ChordFunction,
+  omitted: Vec<ChordFunction>,
+  penalty: usize,
+}
+
+impl ChordMatch {
+  /// Returns the matched [`Chord`]; slash chords carry their bass.
+  #[must_use]
+  pub const fn chord(&self) -> &Chord { &self.chord }
+
+  /// Returns the [`ChordFunction`] of the lowest pitch.
+  #[must_use]
+  pub const fn bass_function(&self) -> ChordFunction { self.bass_function }
+
+  /// Returns the inversion of the [`ChordMatch`]: 0 for root position, then
+  /// 1, 2, and 3 for the third, fifth, and seventh in the bass, or [`None`]
+  /// for any other tone in the bass.
+  #[must_use]
+  pub const fn inversion(&self) -> Option<usize> {
+    match self.bass_function {
+      ChordFunction::Root => Some(0),
+      ChordFunction::Third => Some(1),
+      ChordFunction::Fifth => Some(2),
+      ChordFunction::Seventh => Some(3),
+      _ => None,
+    }
+  }
+
+  /// Returns the [`ChordFunction`]s of the [`Chord`] which weren't present,
+  /// such as an omitted fifth or the root of a rootless voicing.
+  #[must_use]
+  pub fn omitted(&self) -> &[ChordFunction] { &self.omitted }
+
+  /// Returns whether the root of the [`Chord`] wasn't present.
+  #[must_use]
+  pub fn is_rootless(&self) -> bool {
+    self.omitted.contains(&ChordFunction::Root)
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Triad {
+  Major,
+  Minor,
+  Diminished,
+  HalfDiminished,
+  Augmented,
+}
+
+/// A chord, as written in a chord symbol: a root, the [`Interval`]s of its
+/// tones above the root, and an optional bass note for slash chords.
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::Chord;
+///
+/// let chord = "F#m7b5".parse::<Chord>().unwrap();
+/// let tones = chord
+///   .tones(4)
+///   .iter()
+///   .map(|tone| tone.pitch().pitch_class().to_string())
+///   .collect::<Vec<_>>();
+///
+/// assert_eq!(tones, ["F#", "A", "C", "E"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Chord {
+  root: PitchClass,
+  suffix: String,
+  intervals: Vec<Interval>,
+  bass: Option<PitchClass>,
+}
+
+impl Chord {
+  /// Names a set of [`Pitch`]es, returning every matching [`Chord`] from the
+  /// most to the least likely.
+  ///
+  /// The lowest [`Pitch`] is taken as the bass, so inversions are named as
+  /// slash chords. Voicings which leave out the fifth of a four-note chord,
+  /// or the root of a seventh chord, are matched with a lower rank.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{Chord, Pitch};
+  ///
+  /// let pitches = ["E3", "C4", "G4"].map(|name| name.parse::<Pitch>().unwrap());
+  /// let matches = Chord::identify(&pitches);
+  ///
+  /// assert_eq!(matches[0].chord().to_string(), "C/E");
+  /// assert_eq!(matches[0].inversion(), Some(1));
+  /// ```
+  #[must_use]
+  pub fn identify(pitches: &[Pitch]) -> Vec<ChordMatch> {
+    let Some(bass) = pitches.iter().min_by_key(|pitch| pitch.midi_note())
+    else {
+      return vec![];
+    };
+    let mut classes = pitches
+      .iter()
+      .map(|pitch| pitch.pitch_class().semitones())
+      .collect::<Vec<_>>();
+
+    classes.sort_unstable();
+    classes.dedup();
+
+    if classes.len() < 2 {
+      return vec![];
+    }
+
+    let mut matches = Vec::new();
+
+    for (rank, template) in TEMPLATES.iter().enumerate() {
+      let Ok(shape) = format!("C{template}").parse::<Self>() else {
+        continue;
+      };
+
+      for root in 0..12 {
+        if let Some(found) = shape.match_pitches(pitches, bass, &classes, root)
+        {
+          matches.push((found.penalty, shape.intervals.len(), rank, found));
+        }
+      }
+    }
+
+    matches
+      .sort_by_key(|(penalty, length, rank, _)| (*penalty, *length, *rank));
+    matches.into_iter().map(|(_, _, _, found)| found).collect()
+  }
+
+  /// Matches a template [`Chord`] rooted on a number of [`Semitone`]s above C
+  /// against a set of pitches.
+  fn match_pitches(
+    &self,
+    pitches: &[Pitch],
+    bass: &Pitch,
+    classes: &[Semitone],
+    root: Semitone,
+  ) -> Option<ChordMatch> {
+    let class_of = |interval: &Interval| (root + interval.semitones()) % 12;
+    let function_of = |class| {
+      self
+        .intervals
+        .iter()
+        .find(|interval| class_of(interval) == class)
+        .map(|interval| ChordFunction::from_interval(*interval))
+    };
+
+    if classes.iter().any(|class| function_of(*class).is_none()) {
+      return None;
+    }
+
+    let omitted = self
+      .intervals
+      .iter()
+      .filter(|interval| !classes.contains(&class_of(interval)))
+      .map(|interval| ChordFunction::from_interval(*interval))
+      .collect::<Vec<_>>();
+    let allowed = omitted.iter().all(|function| match function {
+      ChordFunction::Fifth =>
+        self.intervals.len() >= 4
+          && self.intervals.contains(&Interval::PERFECT_FIFTH),
+      ChordFunction::Root =>
+        self.intervals.len() >= 4
+          && classes.len() >= 3
+          && self.intervals.iter().any(|i| i.number() == 7),
+      _ => false,
+    });
+
+    if !allowed {
+      return None;
+    }
+
+    let rootless = omitted.contains(&ChordFunction::Root);
+    let root_class = if rootless {
+      let (pitch, interval) = pitches.iter().find_map(|pitch| {
+        self
+          .intervals
+          .iter()
+          .find(|interval| {
+            class_of(interval) == pitch.pitch_class().semitones()
+          })
+          .map(|interval| (pitch, *interval))
+      })?;
+
+      (pitch - interval).pitch_class()
+    } else {
+      pitches
+        .iter()
+        .find(|pitch| pitch.pitch_class().semitones() == root)?
+        .pitch_class()
+    };
+    let bass_function = function_of(bass.pitch_class().semitones())?;
+    let slash = !rootless && bass_function != ChordFunction::Root;
+    let chord = Self {
+      root: root_class,
+      suffix: self.suffix.clone(),
+      intervals: self.intervals.clone(),
+      bass: slash.then(|| bass.pitch_class()),
+    };
+
+    Some(ChordMatch {
+      chord,
+      bass_function,
+      penalty: omitted.len() + usize::from(rootless) * 3 + usize::from(slash),
+      omitted,
+    })
+  }
+
+  /// Returns the root of the [`Chord`].
+  #[must_use]
+  pub const fn root(&self) -> PitchClass { self.root }
+
+  /// Returns the part of the chord symbol after the root and before the bass,
+  /// e.g., "maj7#11" for "Cmaj7#11/E".
+  #[must_use]
+  pub fn suffix(&self) -> &str { &self.suffix }
+
+  /// Returns the [`Interval`]s of the [`Chord`]'s tones above its root,
+  /// including the perfect unison of the root itself.
+  #[must_use]
+  pub fn intervals(&self) -> &[Interval] { &self.intervals }
+
+  /// Returns the bass of a slash chord, if it has one.
+  #[must_use]
+  pub const fn bass(&self) -> Option<PitchClass> { self.bass }
+
+  /// Returns the spelled [`PitchClass`]es of the [`Chord`], starting from its
+  /// root.
+  #[must_use]
+  pub fn pitch_classes(&self) -> Vec<PitchClass> {
+    self
+      .tones(4)
+      .iter()
+      .map(|tone| tone.pitch.pitch_class())
+      .collect()
+  }
+
+  /// Returns the [`ChordTone`]s of the [`Chord`], stacked above its root in
+  /// an [`Octave`].
+  #[must_use]
+  pub fn tones(&self, octave: Octave) -> Vec<ChordTone> {
+    let root = Pitch::new_from_pitch_class(self.root, octave);
+
+    self
+      .intervals
+      .iter()
+      .map(|&interval| ChordTone {
+        pitch: &root + interval,
+        interval,
+      })
+      .collect()
+  }
+
+  /// Returns the lowest [`Pitch`] of the [`Chord`] when its root is in an
+  /// [`Octave`]: the bass of a slash chord, placed below the root, or the
+  /// root itself.
+  #[must_use]
+  pub fn bass_pitch(&self, octave: Octave) -> Pitch {
+    let root = Pitch::new_from_pitch_class(self.root, octave);
+
+    self.bass.map_or_else(
+      || root.clone(),
+      |bass| {
+        let bass = Pitch::new_from_pitch_class(bass, octave);
+
+        if bass.midi_note() < root.midi_note() {
+          bass
+        } else {
+          Pitch::new_from_pitch_class(bass.pitch_class(), octave - 1)
+        }
+      },
+    )
+  }
+
+  /// Applies the quality and extension at the start of a chord symbol's
+  /// modifiers, e.g., "m7" or "maj9".
+  fn apply_quality(&mut self, rest: &mut &str) -> Result<(), ChordError> {
+    let mut triad = Triad::Major;
+    let mut major_seventh = false;
+    let mut implied_seventh = false;
+
+    if take(rest, &["mMaj", "mmaj", "minmaj", "mM", "-maj", "-Δ"]).is_some() {
+      triad = Triad::Minor;
+      major_seventh = true;
+    } else if let Some(prefix) =
+      take(rest, &["maj", "Maj", "MA", "M", "Δ", "^"])
+    {
+      major_seventh = true;
+      implied_seventh = matches!(prefix, "Δ" | "^");
+    } else if take(rest, &["min", "mi", "m", "-"]).is_some() {
+      triad = Triad::Minor;
+    } else if !rest.starts_with("omit")
+      && take(rest, &["dim", "°", "o"]).is_some()
+    {
+      triad = Triad::Diminished;
+    } else if take(rest, &["ø", "Ø"]).is_some() {
+      triad = Triad::HalfDiminished;
+      implied_seventh = true;
+    } else if take(rest, &["aug", "+"]).is_some() {
+      triad = Triad::Augmented;
+    }
+
+    if !major_seventh && take(rest, &["maj", "M", "Δ"]).is_some() {
+      major_seventh = true;
+      implied_seventh = true;
+    }
+
+    let (third, fifth) = match triad {
+      Triad::Major => (Interval::MAJOR_THIRD, Interval::PERFECT_FIFTH),
+      Triad::Minor => (Interval::MINOR_THIRD, Interval::PERFECT_FIFTH),
+      Triad::Diminished | Triad::HalfDiminished =>
+        (Interval::MINOR_THIRD, Interval::DIMINISHED_FIFTH),
+      Triad::Augmented => (Interval::MAJOR_THIRD, Interval::AUGMENTED_FIFTH),
+    };
+    let seventh = if major_seventh {
+      Interval::MAJOR_SEVENTH
+    } else if triad == Triad::Diminished {
+      Interval::DIMINISHED_SEVENTH
+    } else {
+      Interval::MINOR_SEVENTH
+    };
+
+    self.push(third);
+    self.push(fifth);
+
+    if take(rest, &["69", "6/9"]).is_some() {
+      self.push(Interval::MAJOR_SIXTH);
+      self.push(Interval::MAJOR_NINTH);
+    } else if rest.starts_with(|c: char| c.is_ascii_digit()) {
+      match take_number(rest) {
+        Some(5) => self.remove_number(3),
+        Some(6) => self.push(Interval::MAJOR_SIXTH),
+        Some(number @ (7 | 9 | 11 | 13)) => {
+          self.push(seventh);
+
+          if number >= 9 {
+            self.push(Interval::MAJOR_NINTH);
+          }
+
+          // Dominant and major thirteenth chords conventionally leave out
+          // the eleventh, which clashes with the major third.
+          if number == 11 || (number == 13 && triad != Triad::Major) {
+            self.push(Interval::PERFECT_FOURTH.compound(1));
+          }
+
+          if number == 13 {
+            self.push(Interval::MAJOR_SIXTH.compound(1));
+          }
+        }
+        number =>
+          return Err(ChordError::UnknownModifier(format!(
+            "{}{rest}",
+            number.map(|number| number.to_string()).unwrap_or_default()
+          ))),
+      }
+    } else if implied_seventh {
+      self.push(seventh);
+    }
+
+    Ok(())
+  }
+
+  /// Applies one modifier from the rest of a chord symbol, e.g., "sus4",
+  /// "add9", "b5", or "no3".
+  fn apply_modifier(&mut self, rest: &mut &str) -> Result<(), ChordError> {
+    let modifier = *rest;
+    let unknown = || ChordError::UnknownModifier(modifier.to_string());
+
+    if let Some(sus) = take(rest, &["sus4", "sus2", "sus"]) {
+      self.remove_number(3);
+      self.push(if sus == "sus2" {
+        Interval::MAJOR_SECOND
+      } else {
+        Interval::PERFECT_FOURTH
+      });
+    } else if take(rest, &["alt"]).is_some() {
+      self.remove_number(5);
+      self.push(Interval::MINOR_SEVENTH);
+
+      for mut tension in ["b9", "#9", "#11", "b13"] {
+        if let Some(interval) = take_tension(&mut tension) {
+          self.alter(interval);
+        }
+      }
+    } else if take(rest, &["no", "omit"]).is_some() {
+      match take_number(rest) {
+        Some(number @ (1 | 3 | 5)) => self.remove_number(number),
+        _ => return Err(unknown()),
+      }
+    } else if take(rest, &["add"]).is_some() {
+      self.push(take_tension(rest).ok_or_else(unknown)?);
+    } else if rest.starts_with(['b', '#', '♭', '♯']) {
+      self.alter(take_tension(rest).ok_or_else(unknown)?);
+    } else {
+      return Err(unknown());
+    }
+
+    Ok(())
+  }
+
+  fn remove_number(&mut self, number: IntervalNumber) {
+    self
+      .intervals
+      .retain(|interval| interval.number() != number);
+  }
+
+  fn push(&mut self, interval: Interval) {
+    if !self.intervals.contains(&interval) {
+      self.intervals.push(interval);
+    }
+  }
+
+  /// Adds a tension such as a flat nine, replacing the unaltered tone of the
+  /// same number.
+  fn alter(&mut self, interval: Interval) {
+    let natural = natural_interval(interval.number());
+
+    self.intervals.retain(|existing| Some(*existing) != natural);
+    self.push(interval);
+  }
+}
+
+/// Returns the perfect or major [`Interval`] of a number.
+fn natural_interval(number: IntervalNumber) -> Option<Interval> {
+  Interval::new(Quality::Perfect, number)
+    .or_else(|| Interval::new(Quality::Major, number))
+}
+
+/// Removes the first matching prefix from `rest`, returning it.
+fn take<'a>(rest: &mut &str, prefixes: &[&'a str]) -> Option<&'a str> {
+  let prefix = prefixes.iter().find(|prefix| rest.starts_with(**prefix))?;
+
+  *rest = &rest[prefix.len()..];
+
+  Some(prefix)
+}
+
+/// Removes a leading number from `rest`, returning it.
+fn take_number(rest: &mut &str) -> Option<IntervalNumber> {
+  let length = rest
+    .find(|character: char| !character.is_ascii_digit())
+    .unwrap_or(rest.len());
+  let number = rest[..length].parse().ok()?;
+
+  *rest = &rest[length..];
+
+  Some(number)
+}
+
+/// Removes a tension such as "b9", "#11", or "13" from `rest`, returning its
+/// [`Interval`] above the root.
+fn take_tension(rest: &mut &str) -> Option<Interval> {
+  let alteration = match take(rest, &["b", "♭", "#", "♯"]) {
+    Some("b" | "♭") => -1,
+    Some(_) => 1,
+    None => 0,
+  };
+  let number = take_number(rest)?;
+  let perfect = Interval::new(Quality::Perfect, number).is_some();
+  let quality = match (alteration, perfect) {
+    (-1, true) => Quality::Diminished(1),
+    (-1, false) => Quality::Minor,
+    (0, true) => Quality::Perfect,
+    (0, false) => Quality::Major,
+    _ => Quality::Augmented(1),
+  };
+
+  Interval::new(quality, number)
+}
+
+impl Transpose for Chord {
+  /// Moves the root and bass of the [`Chord`], keeping its quality.
+  fn transposed(&self, transposition: Transposition) -> Self {
+    Self {
+      root: transposition.pitch_class(self.root),
+      suffix: self.suffix.clone(),
+      intervals: self.intervals.clone(),
+      bass: self.bass.map(|bass| transposition.pitch_class(bass)),
+    }
+  }
+}
+
+impl fmt::Display for Chord {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}{}", self.root, self.suffix)?;
+
+    if let Some(bass) = self.bass {
+      write!(f, "/{bass}")?;
+    }
+
+    Ok(())
+  }
+}
+
+impl FromStr for Chord {
+  type Err = ChordError;
+
+  /// Parses a chord symbol such as "Cmaj7#11/E", "F#m7b5", "G13sus4",
+  /// "Bbadd9", "E7alt", "C6/9", "Dø7", or "A°".
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let symbol = s.trim();
+    let first = symbol.chars().next().ok_or(ChordError::Empty)?;
+    let (symbol, bass) = match symbol.rfind('/') {
+      Some(slash)
+        if symbol[slash + 1..].starts_with(|c: char| c.is_alphabetic()) =>
+        (
+          &symbol[..slash],
+          Some(
+            symbol[slash + 1..]
+              .parse::<PitchClass>()
+              .map_err(ChordError::InvalidBass)?,
+          ),
+        ),
+      _ => (symbol, None),
+    };
+    let root_length = first.len_utf8()
+      + symbol[first.len_utf8()..]
+        .chars()
+        .take_while(|c| matches!(c, 'b' | '#' | '♭' | '♯'))
+        .map(char::len_utf8)
+        .sum::<usize>();
+    let (root, suffix) = symbol.split_at(root_length);
+    let modifiers = suffix
+      .chars()
+      .filter(|c| !matches!(c, '(' | ')' | ',' | ' '))
+      .collect::<String>();
+    let mut rest = modifiers.as_str();
+    let mut chord = Self {
+      root: root.parse().map_err(ChordError::InvalidRoot)?,
+      suffix: suffix.to_string(),
+      intervals: vec![Interval::PERFECT_UNISON],
+      bass,
+    };
+
+    chord.apply_quality(&mut rest)?;
+
+    while !rest.is_empty() {
+      chord.apply_modifier(&mut rest)?;
+    }
+
+    chord
+      .intervals
+      .sort_by_key(|interval| (interval.number(), interval.semitones()));
+
+    Ok(chord)
+  }
+}
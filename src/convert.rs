@@ -22,10 +22,10 @@
 )]
 
 use crate::{
+  key::Key,
   pitch::Pitch,
+  pitch_class::PitchClass,
   unit::{Cent, Frequency, MidiNote, Octave, Semitone},
-  utility::capitalize_first_letter,
-  NOTES,
 };
 
 /// Finds the [`Frequency`] of pitch given the number of [`Semitone`]s above a
@@ -53,7 +53,8 @@ pub fn semitones_to_frequency(
 ///
 /// The number of [`Semitone`]s is rounded to the nearest integer. If you want
 /// to get the non-equal number of [`Semitone`]s, use
-/// [`frequency_to_non_equal_semitones`].
+/// [`frequency_to_non_equal_semitones`], or use
+/// [`frequency_to_semitones_and_cents`] to keep the remainder.
 ///
 /// # Examples
 ///
@@ -65,7 +66,31 @@ pub fn frequency_to_semitones(
   frequency: Frequency,
   base_frequency: Frequency,
 ) -> Semitone {
-  (12. * (frequency / base_frequency).log2()).round() as Semitone
+  frequency_to_semitones_and_cents(frequency, base_frequency).0
+}
+
+/// Finds the number of [`Semitone`]s between the [`Frequency`] and a base
+/// [`Frequency`], rounded to the nearest integer, along with the signed
+/// number of [`Cent`]s which the rounding threw away.
+///
+/// # Examples
+///
+/// ```rust
+/// let (semitones, cents) =
+///   guitar::convert::frequency_to_semitones_and_cents(445., 440.);
+///
+/// assert_eq!(semitones, 0);
+/// assert!((cents - 19.56).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn frequency_to_semitones_and_cents(
+  frequency: Frequency,
+  base_frequency: Frequency,
+) -> (Semitone, Cent) {
+  let semitones = frequency_to_non_equal_semitones(frequency, base_frequency);
+  let nearest = semitones.round();
+
+  (nearest as Semitone, (semitones - nearest) * 100.)
 }
 
 /// Finds the number of [`Semitone`]s between the [`Frequency`] and a base
@@ -128,11 +153,32 @@ pub fn frequency_to_midi_note(
   frequency: Frequency,
   base_frequency: Frequency,
 ) -> MidiNote {
+  frequency_to_midi_note_and_cents(frequency, base_frequency).0
+}
+
+/// Finds the MIDI note of a [`Frequency`], with respect to a base
+/// [`Frequency`], along with the signed number of [`Cent`]s which the
+/// [`Frequency`] deviates from it.
+///
+/// # Examples
+///
+/// ```rust
+/// let (midi_note, cents) =
+///   guitar::convert::frequency_to_midi_note_and_cents(435., 440.);
+///
+/// assert_eq!(midi_note, 69);
+/// assert!((cents + 19.79).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn frequency_to_midi_note_and_cents(
+  frequency: Frequency,
+  base_frequency: Frequency,
+) -> (MidiNote, Cent) {
   let a4_midi_note = 69;
-  let semitones = frequency_to_semitones(frequency, base_frequency);
-  let midi_note = a4_midi_note + semitones;
+  let (semitones, cents) =
+    frequency_to_semitones_and_cents(frequency, base_frequency);
 
-  midi_note as MidiNote
+  (a4_midi_note + semitones, cents)
 }
 
 /// Find the number of [`Cent`]s of a pitch given the number of [`Semitone`]s
@@ -148,6 +194,19 @@ pub fn semitones_to_cents(semitones: Semitone) -> Cent {
   semitones as f64 * 100.
 }
 
+/// Finds the [`Frequency`] of a pitch given the number of [`Cent`]s above a
+/// base [`Frequency`].
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(guitar::convert::cents_to_frequency(1200., 440.), 880.);
+/// ```
+#[must_use]
+pub fn cents_to_frequency(cents: Cent, base_frequency: Frequency) -> Frequency {
+  base_frequency * (cents / 1200.).exp2()
+}
+
 /// Find the number of [`Semitone`]s of a pitch given the number of [`Cent`]s.
 ///
 /// # Examples
@@ -177,84 +236,97 @@ pub fn cents_between_frequencies(
   1200. * (second_frequency / first_frequency).log2()
 }
 
-/// Finds the enharmonic of a pitch (as a string), if it exists.
-#[must_use]
-pub fn enharmonic(pitch: &str) -> Option<String> {
-  let pitch = capitalize_first_letter(pitch);
-  let enharmonic_note;
-  let natural_note = if pitch.ends_with('b') || pitch.ends_with('#') {
-    &pitch[..1]
-  } else {
-    return None;
-  };
-  // This `unwrap` *should* never fail...
-  let note_index = NOTES.iter().position(|&n| n == natural_note).unwrap_or(0);
-  let enharmonic_index;
-
-  if pitch.ends_with('#') {
-    enharmonic_index = (note_index + 1) % 12;
-
-    enharmonic_note = NOTES[enharmonic_index + 1].to_string() + "b";
-  } else if pitch.ends_with('b') {
-    enharmonic_index = (note_index - 1) % 12;
-
-    enharmonic_note = NOTES[enharmonic_index - 1].to_string() + "#";
-  } else {
-    return None;
-  };
-
-  Some(enharmonic_note)
-}
-
-/// Find the number of [`Semitone`]s above any base [`Frequency`] given a
-/// pitch.
+/// Find the number of [`Semitone`]s above A4 given a [`PitchClass`] and its
+/// [`Octave`].
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::PitchClass;
+///
+/// let semitones = |name| {
+///   guitar::convert::pitch_and_octave_to_semitones(
+///     PitchClass::from_name(name).unwrap(),
+///     4,
+///   )
+/// };
+///
+/// assert_eq!(semitones("A"), 0);
+/// assert_eq!(semitones("Cb"), -10);
+/// assert_eq!(semitones("B#"), 3);
+/// ```
 #[must_use]
-pub fn pitch_and_octave_to_semitones(pitch: &str, octave: Octave) -> Semitone {
-  let pitch = capitalize_first_letter(pitch);
-  let pitch_offset =
-    i64::from(*crate::notes::NOTES_OFFSET.get(&pitch).unwrap_or(&0));
-  let semitones = pitch_offset + (octave - 4) * 12;
-
-  semitones as Semitone
+pub const fn pitch_and_octave_to_semitones(
+  pitch: PitchClass,
+  octave: Octave,
+) -> Semitone {
+  pitch.offset() - 9 + (octave - 4) * 12
 }
 
 /// Encapsulate a pitch as a [`Pitch`] given the [`Frequency`] and a base
 /// [`Frequency`].
 ///
+/// The [`Pitch`] is spelled as the nearest equal-tempered note, and keeps the
+/// exact [`Frequency`] along with its deviation from that note in [`Cent`]s.
+///
 /// # Examples
 ///
 /// ```rust
 /// assert_eq!(
-///   guitar::convert::frequency_to_note(466.16, 440., 69).semitones(),
+///   guitar::convert::frequency_to_pitch(466.16, 440., 69).semitones(),
 ///   guitar::Pitch::new("A#", 4).semitones(),
 /// );
+/// ```
 #[must_use]
 pub fn frequency_to_pitch(
   frequency: Frequency,
   base_frequency: Frequency,
   base_midi_note: MidiNote,
 ) -> Pitch {
-  let semitones_above_a4 = 12. * (frequency / base_frequency).log2();
-  let nearest_midi_pitch = semitones_above_a4.round();
-  let midi_pitch = nearest_midi_pitch + base_midi_note as f64;
-  let octave = (midi_pitch / 12.).floor() - 1.;
-  let pitch = match (midi_pitch % 12.) as u64 {
-    0 => "C",
-    1 => "C#",
-    2 => "D",
-    3 => "D#",
-    4 => "E",
-    5 => "F",
-    6 => "F#",
-    7 => "G",
-    8 => "G#",
-    9 => "A",
-    10 => "A#",
-    11 => "B",
-    _ => unreachable!(),
-  };
+  let (semitones_above_a4, _) =
+    frequency_to_semitones_and_cents(frequency, base_frequency);
+  let midi_pitch = semitones_above_a4 + base_midi_note;
+
+  Pitch::new_from_complete(
+    PitchClass::from_semitones(midi_pitch),
+    frequency,
+    base_frequency,
+    base_midi_note,
+    midi_pitch.div_euclid(12) - 1,
+  )
+}
 
-  Pitch::new(pitch, octave as Octave)
+/// Encapsulate a pitch as a [`Pitch`] given the [`Frequency`] and a base
+/// [`Frequency`], spelled in the context of a [`Key`].
+///
+/// Unlike [`frequency_to_pitch`], which always spells with sharps, this
+/// spells with flats in flat keys.
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::{Key, PitchClass};
+///
+/// let b_flat = Key::major(PitchClass::from_name("Bb").unwrap());
+///
+/// assert_eq!(
+///   guitar::convert::frequency_to_pitch_in_key(311.13, 440., 69, b_flat)
+///     .to_string(),
+///   "Eb4",
+/// );
+/// ```
+#[must_use]
+pub fn frequency_to_pitch_in_key(
+  frequency: Frequency,
+  base_frequency: Frequency,
+  base_midi_note: MidiNote,
+  key: Key,
+) -> Pitch {
+  key.spell(&frequency_to_pitch(
+    frequency,
+    base_frequency,
+    base_midi_note,
+  ))
 }
 
 /// Finds the [`Frequency`] of a [`MidiNote`] with respect to a base
@@ -272,21 +344,63 @@ pub fn midi_note_to_frequency(
 /// base [`Frequency`] and base [`MidiNote`].
 #[must_use]
 pub fn pitch_and_octave_to_frequency(
-  pitch: &str,
+  pitch: PitchClass,
   octave: Octave,
   base_frequency: Frequency,
   base_midi_note: MidiNote,
 ) -> Frequency {
-  let pitch = capitalize_first_letter(pitch);
-  // This used to work, but sometime when I moved the [`Pitch`] related things
-  // out of [`Note`], it stopped working. I messed around and after a few
-  // seconds I realized that the now working solution, under it, works...
-  // I'm not sure why, but I'm not going to question it.
-
-  // let semitones_above_a4 =
-  //   pitch_and_octave_to_semitones(pitch, octave) + (octave - 4) * 12;
-  let semitones_above_a4 = pitch_and_octave_to_semitones(&pitch, octave);
-  let midi_pitch = semitones_above_a4 + base_midi_note;
+  let semitones_above_a4 = pitch_and_octave_to_semitones(pitch, octave);
+  let midi_pitch = semitones_above_a4 + 69;
 
   midi_note_to_frequency(midi_pitch as MidiNote, base_frequency, base_midi_note)
 }
+
+/// Finds the [`Octave`] of a pitch written in Helmholtz pitch notation, given
+/// whether its letter is uppercase and the octave marks which follow it.
+///
+/// Lowercase letters start at octave 3 and rise with each `'`, while uppercase
+/// letters start at octave 2 and fall with each `,`.
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::convert::helmholtz_marks_to_octave;
+///
+/// assert_eq!(helmholtz_marks_to_octave(false, "''"), Some(5));
+/// assert_eq!(helmholtz_marks_to_octave(true, ","), Some(1));
+/// assert_eq!(helmholtz_marks_to_octave(true, "'"), None);
+/// ```
+#[must_use]
+pub fn helmholtz_marks_to_octave(
+  uppercase: bool,
+  marks: &str,
+) -> Option<Octave> {
+  let count = Octave::try_from(marks.chars().count()).ok()?;
+
+  if uppercase && marks.chars().all(|mark| mark == ',') {
+    Some(2 - count)
+  } else if !uppercase && marks.chars().all(|mark| matches!(mark, '\'' | '′'))
+  {
+    Some(3 + count)
+  } else {
+    None
+  }
+}
+
+/// Finds the Helmholtz octave marks of an [`Octave`]; the inverse of
+/// [`helmholtz_marks_to_octave`].
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(guitar::convert::octave_to_helmholtz_marks(5), "''");
+/// assert_eq!(guitar::convert::octave_to_helmholtz_marks(0), ",,");
+/// ```
+#[must_use]
+pub fn octave_to_helmholtz_marks(octave: Octave) -> String {
+  if octave >= 3 {
+    "'".repeat((octave - 3) as usize)
+  } else {
+    ",".repeat((2 - octave) as usize)
+  }
+}
@@ -0,0 +1,234 @@
+// This file is part of Guitar <https://github.com/Fuwn/guitar>.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+//
+// Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::fmt;
+
+use crate::{
+  interval::Quality,
+  unit::{Frequency, IntervalNumber, Octave},
+};
+
+/// An error which can occur when parsing or constructing a
+/// [`Pitch`](crate::Pitch).
+#[derive(Debug, Clone, PartialEq)]
+pub enum PitchError {
+  /// The input was empty.
+  Empty,
+  /// The input didn't start with one of the letters A through G.
+  UnknownLetter(char),
+  /// The letter was followed by something which isn't an
+  /// [`Accidental`](crate::Accidental).
+  InvalidAccidental(String),
+  /// The octave was neither a number nor Helmholtz octave marks.
+  InvalidOctave(String),
+  /// The octave was followed by something which isn't a number of cents.
+  InvalidCentOffset(String),
+  /// The octave was outside of [`Pitch::MIN_OCTAVE`] and
+  /// [`Pitch::MAX_OCTAVE`].
+  ///
+  /// [`Pitch::MIN_OCTAVE`]: crate::Pitch::MIN_OCTAVE
+  /// [`Pitch::MAX_OCTAVE`]: crate::Pitch::MAX_OCTAVE
+  OctaveOutOfRange(Octave),
+  /// The frequency was infinite or NaN.
+  NonFiniteFrequency(Frequency),
+  /// The frequency was zero or negative.
+  NonPositiveFrequency(Frequency),
+}
+
+impl fmt::Display for PitchError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Empty => write!(f, "empty pitch"),
+      Self::UnknownLetter(letter) => write!(f, "unknown letter {letter:?}"),
+      Self::InvalidAccidental(accidental) =>
+        write!(f, "invalid accidental {accidental:?}"),
+      Self::InvalidOctave(octave) => write!(f, "invalid octave {octave:?}"),
+      Self::InvalidCentOffset(cents) =>
+        write!(f, "invalid cent offset {cents:?}"),
+      Self::OctaveOutOfRange(octave) => write!(
+        f,
+        "octave {octave} is outside of {}..={}",
+        crate::Pitch::MIN_OCTAVE,
+        crate::Pitch::MAX_OCTAVE
+      ),
+      Self::NonFiniteFrequency(frequency) =>
+        write!(f, "frequency {frequency} is not finite"),
+      Self::NonPositiveFrequency(frequency) =>
+        write!(f, "frequency {frequency} is not positive"),
+    }
+  }
+}
+
+impl std::error::Error for PitchError {}
+
+/// An error which can occur when parsing an [`Interval`](crate::Interval).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntervalError {
+  /// The quality wasn't one of "P", "M", "m", or a run of "d" or "A".
+  InvalidQuality(String),
+  /// The number wasn't a positive integer.
+  InvalidNumber(String),
+  /// The quality can't be applied to the number (e.g., "P3" or "M5").
+  ImpossibleQuality(Quality, IntervalNumber),
+}
+
+impl fmt::Display for IntervalError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::InvalidQuality(quality) =>
+        write!(f, "invalid interval quality {quality:?}"),
+      Self::InvalidNumber(number) =>
+        write!(f, "invalid interval number {number:?}"),
+      Self::ImpossibleQuality(quality, number) =>
+        write!(f, "there is no interval {quality}{number}"),
+    }
+  }
+}
+
+impl std::error::Error for IntervalError {}
+
+/// An error which can occur when parsing a [`Chord`](crate::Chord) symbol.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChordError {
+  /// The chord symbol was empty.
+  Empty,
+  /// The root of the chord symbol wasn't a valid
+  /// [`PitchClass`](crate::PitchClass).
+  InvalidRoot(PitchError),
+  /// The bass of a slash chord wasn't a valid
+  /// [`PitchClass`](crate::PitchClass).
+  InvalidBass(PitchError),
+  /// Part of the chord symbol after the root wasn't understood.
+  UnknownModifier(String),
+}
+
+impl fmt::Display for ChordError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Empty => write!(f, "empty chord symbol"),
+      Self::InvalidRoot(error) => write!(f, "invalid chord root: {error}"),
+      Self::InvalidBass(error) => write!(f, "invalid chord bass: {error}"),
+      Self::UnknownModifier(modifier) =>
+        write!(f, "unknown chord modifier {modifier:?}"),
+    }
+  }
+}
+
+impl std::error::Error for ChordError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      Self::InvalidRoot(error) | Self::InvalidBass(error) => Some(error),
+      _ => None,
+    }
+  }
+}
+
+/// An error which can occur when parsing a Scala scale (.scl) or keyboard
+/// mapping (.kbm) file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScalaError {
+  /// The file ended before all of its required lines were read.
+  UnexpectedEnd,
+  /// A line which should hold a count, MIDI note, or scale degree didn't.
+  InvalidNumber(String),
+  /// A line which should hold a frequency didn't.
+  InvalidFrequency(String),
+  /// A scale line was neither a number of cents nor a positive ratio.
+  InvalidPitch(String),
+  /// The scale had no pitches, so it doesn't repeat.
+  EmptyScale,
+}
+
+impl fmt::Display for ScalaError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::UnexpectedEnd => write!(f, "unexpected end of scala file"),
+      Self::InvalidNumber(number) => write!(f, "invalid number {number:?}"),
+      Self::InvalidFrequency(frequency) =>
+        write!(f, "invalid frequency {frequency:?}"),
+      Self::InvalidPitch(pitch) => write!(f, "invalid scale pitch {pitch:?}"),
+      Self::EmptyScale => write!(f, "scale has no pitches"),
+    }
+  }
+}
+
+impl std::error::Error for ScalaError {}
+
+/// An error which can occur when parsing a [`Tuning`](crate::Tuning).
+#[derive(Debug, Clone, PartialEq)]
+pub enum TuningError {
+  /// The tuning was empty.
+  Empty,
+  /// One of the strings wasn't a valid [`Pitch`](crate::Pitch).
+  InvalidPitch(PitchError),
+}
+
+impl fmt::Display for TuningError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Empty => write!(f, "empty tuning"),
+      Self::InvalidPitch(error) => write!(f, "invalid tuning pitch: {error}"),
+    }
+  }
+}
+
+impl std::error::Error for TuningError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      Self::InvalidPitch(error) => Some(error),
+      Self::Empty => None,
+    }
+  }
+}
+
+/// An error which can occur when changing a [`Fretboard`](crate::Fretboard).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FretboardError {
+  /// A partial [`Capo`](crate::Capo) covered a string which the fretboard
+  /// doesn't have.
+  CapoStringOutOfRange {
+    /// The index of the missing string.
+    string: usize,
+    /// The number of strings on the fretboard.
+    strings: usize,
+  },
+  /// A [`Neck`](crate::Neck) had a different number of strings than the
+  /// fretboard.
+  NeckStringCount {
+    /// The number of strings on the neck.
+    neck: usize,
+    /// The number of strings on the fretboard.
+    strings: usize,
+  },
+}
+
+impl fmt::Display for FretboardError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::CapoStringOutOfRange { string, strings } => write!(
+        f,
+        "capo covers string {string}, but the fretboard has {strings} strings"
+      ),
+      Self::NeckStringCount { neck, strings } => write!(
+        f,
+        "neck has {neck} strings, but the fretboard has {strings} strings"
+      ),
+    }
+  }
+}
+
+impl std::error::Error for FretboardError {}
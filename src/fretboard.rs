@@ -15,36 +15,175 @@
 // Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
 // SPDX-License-Identifier: GPL-3.0-only
 
-use crate::{string::String, unit::Frets, Pitch};
+#![allow(clippy::cast_precision_loss)]
 
+use std::ops::{RangeBounds, RangeInclusive};
+
+use crate::{
+  capo::Capo,
+  chord::{Chord, ChordMatch},
+  error::FretboardError,
+  neck::Neck,
+  pitch_class::PitchClass,
+  reference::Reference,
+  string::String,
+  transpose::{Transpose, Transposition},
+  tuning::Tuning,
+  unit::{Frequency, Frets, Semitone},
+  Pitch,
+};
+
+/// The neck of a fretted instrument, holding its [`String`]s.
+///
+/// Frets are usually counted from each [`String`]'s own nut, which is the
+/// [`Capo`] where one covers the [`String`]; frets of the neck are counted
+/// from the neck's nut, and differ for [`String`]s which start mid-neck like
+/// the drone string of a five-string banjo.
 pub struct Fretboard {
   strings: Vec<String>,
   frets: Frets,
+  reference: Reference,
+  capo: Option<Capo>,
+  neck: Option<Neck>,
 }
 
 impl Fretboard {
   /// Create a new [`Fretboard`] from a [`Vec`] of [`String`]s and a number of
   /// [`Frets`].
+  ///
+  /// The [`Fretboard`] takes the [`Reference`] of the first [`String`], and
+  /// [`String`]s tuned relative to another [`Reference`] keep it; use
+  /// [`Fretboard::with_reference`] to tune them all alike.
   #[must_use]
   pub fn new_from_strings(strings: Vec<String>, frets: Frets) -> Self {
-    Self { strings, frets }
+    Self {
+      reference: strings.first().map_or(Reference::A440, String::reference),
+      strings,
+      frets,
+      capo: None,
+      neck: None,
+    }
   }
 
   /// Create a new [`Fretboard`] from a number of [`Frets`], using standard
   /// tuning for a six-string guitar (E2, A2, D3, G3, B3, E4).
   #[must_use]
   pub fn new(frets: Frets) -> Self {
-    Self {
-      strings: vec![
-        String::new(Pitch::new("E", 2), frets),
-        String::new(Pitch::new("A", 2), frets),
-        String::new(Pitch::new("D", 3), frets),
-        String::new(Pitch::new("G", 3), frets),
-        String::new(Pitch::new("B", 3), frets),
-        String::new(Pitch::new("E", 4), frets),
-      ],
+    Self::new_from_tuning(&Tuning::default(), frets)
+  }
+
+  /// Create a new [`Fretboard`] from a [`Tuning`] and a number of [`Frets`].
+  ///
+  /// Every [`String`] is retuned to the [`Reference`] of the first one.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{Fretboard, Tuning};
+  ///
+  /// let fretboard =
+  ///   Fretboard::new_from_tuning(&Tuning::named("Bass").unwrap(), 21);
+  ///
+  /// assert_eq!(fretboard.strings().len(), 4);
+  /// assert_eq!(fretboard.tuning().to_string(), "E1 A1 D2 G2");
+  /// ```
+  #[must_use]
+  pub fn new_from_tuning(tuning: &Tuning, frets: Frets) -> Self {
+    let fretboard = Self::new_from_strings(
+      tuning
+        .pitches()
+        .iter()
+        .map(|pitch| String::new(pitch.clone(), frets))
+        .collect(),
       frets,
-    }
+    );
+    let reference = fretboard.reference;
+
+    fretboard.with_reference(reference)
+  }
+
+  /// Returns the [`Fretboard`] restrung to a [`Tuning`], keeping its number
+  /// of [`Frets`] and its [`Reference`], its [`Capo`] if every [`String`]
+  /// which the [`Capo`] covers is still there, and its [`Neck`] if the
+  /// [`Tuning`] has as many strings.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{Fretboard, Tuning};
+  ///
+  /// let fretboard =
+  ///   Fretboard::new(22).with_tuning(&"DADGAD".parse::<Tuning>().unwrap());
+  ///
+  /// assert_eq!(fretboard.strings()[2].fret(2).unwrap().to_string(), "E3");
+  /// ```
+  #[must_use]
+  pub fn with_tuning(self, tuning: &Tuning) -> Self {
+    let mut fretboard = Self::new_from_tuning(tuning, self.frets);
+
+    fretboard.set_reference(self.reference);
+    fretboard.capo = self.capo.filter(|capo| fretboard.fits_capo(capo));
+    fretboard.neck = self
+      .neck
+      .filter(|neck| neck.strings() == fretboard.strings.len());
+
+    fretboard
+  }
+
+  /// Returns the [`Tuning`] of the open [`String`]s, ignoring the [`Capo`].
+  #[must_use]
+  pub fn tuning(&self) -> Tuning {
+    Tuning::new(
+      self
+        .strings
+        .iter()
+        .map(|string| string.pitch().clone())
+        .collect(),
+    )
+  }
+
+  /// Returns the [`Tuning`] which the open [`String`]s sound with the
+  /// [`Capo`] on, with one [`Pitch`] for every [`String`], even where the
+  /// [`Capo`] lies past its last fret.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{Capo, Fretboard};
+  ///
+  /// let mut fretboard = Fretboard::new(13);
+  ///
+  /// fretboard
+  ///   .set_capo(Some(Capo::new_partial(2, &[1, 2, 3, 4, 5])))
+  ///   .unwrap();
+  ///
+  /// assert_eq!(
+  ///   fretboard.sounding_tuning().to_string(),
+  ///   "E2 B2 E3 A3 C#4 F#4"
+  /// );
+  /// ```
+  #[must_use]
+  #[allow(clippy::cast_possible_wrap)]
+  pub fn sounding_tuning(&self) -> Tuning {
+    Tuning::new(
+      self
+        .strings
+        .iter()
+        .enumerate()
+        .map(|(index, string)| {
+          self.pitch_at(index, 0).cloned().unwrap_or_else(|| {
+            let mut pitch = string.pitch().clone();
+
+            pitch.set_semitones(
+              pitch.semitones()
+                + (self.nut(index) - string.start_fret()) as Semitone,
+            );
+
+            pitch
+          })
+        })
+        .collect(),
+    )
   }
 
   /// Return the [`Vec`] of [`String`]s.
@@ -54,4 +193,461 @@ impl Fretboard {
   /// Return the number of [`Frets`].
   #[must_use]
   pub const fn frets(&self) -> &Frets { &self.frets }
+
+  /// Returns the [`Reference`] which every [`String`] and fret is tuned
+  /// relative to.
+  #[must_use]
+  pub const fn reference(&self) -> Reference { self.reference }
+
+  /// Sets the [`Reference`] which every [`String`] and fret is tuned relative
+  /// to.
+  pub fn set_reference(&mut self, reference: Reference) {
+    self.reference = reference;
+
+    for string in &mut self.strings {
+      string.set_reference(reference);
+    }
+  }
+
+  /// Returns the [`Fretboard`] with every [`String`] and fret tuned relative
+  /// to a [`Reference`].
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{string::String, Fretboard, Pitch, Reference};
+  ///
+  /// let mut high = Pitch::new("E", 4);
+  ///
+  /// high.set_reference(Reference::A440.with_frequency(432.));
+  ///
+  /// let strings =
+  ///   vec![String::new(Pitch::new("E", 2), 12), String::new(high, 12)];
+  /// let mixed = Fretboard::new_from_strings(strings, 12);
+  ///
+  /// assert!(
+  ///   (mixed.strings()[1].fret(0).unwrap().frequency() - 323.63).abs() < 0.01
+  /// );
+  ///
+  /// let fretboard = mixed.with_reference(Reference::A440);
+  ///
+  /// assert!(
+  ///   (fretboard.strings()[1].fret(0).unwrap().frequency() - 329.63).abs() < 0.01
+  /// );
+  /// ```
+  #[must_use]
+  pub fn with_reference(mut self, reference: Reference) -> Self {
+    self.set_reference(reference);
+
+    self
+  }
+
+  /// Returns the [`Capo`] on the [`Fretboard`], if there is one.
+  #[must_use]
+  pub const fn capo(&self) -> Option<&Capo> { self.capo.as_ref() }
+
+  /// Puts a [`Capo`] on the [`Fretboard`], or takes it off with [`None`].
+  ///
+  /// Frets of the strings which the [`Capo`] covers are then counted from the
+  /// [`Capo`] rather than their nut, so fret 0 is the [`Capo`]'s fret.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`FretboardError::CapoStringOutOfRange`] and leaves the
+  /// [`Fretboard`] unchanged if the [`Capo`] is partial and covers a
+  /// [`String`] which the [`Fretboard`] doesn't have.
+  pub fn set_capo(&mut self, capo: Option<Capo>) -> Result<(), FretboardError> {
+    if let Some(&string) =
+      capo.as_ref().and_then(Capo::strings).and_then(|strings| {
+        strings.iter().find(|&&string| string >= self.strings.len())
+      })
+    {
+      return Err(FretboardError::CapoStringOutOfRange {
+        string,
+        strings: self.strings.len(),
+      });
+    }
+
+    self.capo = capo;
+
+    Ok(())
+  }
+
+  /// Returns whether every [`String`] which a [`Capo`] covers exists.
+  fn fits_capo(&self, capo: &Capo) -> bool {
+    capo.strings().is_none_or(|strings| {
+      strings.iter().all(|&string| string < self.strings.len())
+    })
+  }
+
+  /// Returns the physical [`Neck`] of the [`Fretboard`], if one was given.
+  #[must_use]
+  pub const fn neck(&self) -> Option<&Neck> { self.neck.as_ref() }
+
+  /// Gives the [`Fretboard`] a physical [`Neck`], which can be multi-scale,
+  /// or removes it with [`None`]; the [`Pitch`]es of the frets don't change.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`FretboardError::NeckStringCount`] and leaves the [`Fretboard`]
+  /// unchanged if the [`Neck`] has a different number of strings than the
+  /// [`Fretboard`].
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{Fretboard, Neck, Tuning};
+  ///
+  /// let mut fretboard =
+  ///   Fretboard::new_from_tuning(&Tuning::named("Eight-String").unwrap(), 25);
+  ///
+  /// fretboard
+  ///   .set_neck(Some(Neck::new_multiscale(711., 648., 8, 25, 7)))
+  ///   .unwrap();
+  ///
+  /// let neck = fretboard.neck().unwrap();
+  ///
+  /// assert_eq!(neck.strings(), fretboard.strings().len());
+  /// assert_eq!(neck.fret_points(7)[0].0, 0.);
+  /// ```
+  pub fn set_neck(&mut self, neck: Option<Neck>) -> Result<(), FretboardError> {
+    if let Some(neck) = neck
+      .as_ref()
+      .filter(|neck| neck.strings() != self.strings.len())
+    {
+      return Err(FretboardError::NeckStringCount {
+        neck: neck.strings(),
+        strings: self.strings.len(),
+      });
+    }
+
+    self.neck = neck;
+
+    Ok(())
+  }
+
+  /// Returns the fret of the neck which a [`String`] sounds at when played
+  /// open; this is the [`Capo`]'s fret if it covers the [`String`] past its
+  /// start and the [`String`]'s start fret otherwise.
+  #[must_use]
+  pub fn nut(&self, string: usize) -> usize {
+    let start = self.strings.get(string).map_or(0, String::start_fret);
+
+    self
+      .capo
+      .as_ref()
+      .map_or(start, |capo| capo.nut(string).max(start))
+  }
+
+  /// Converts a fret counted from a [`String`]'s nut or [`Capo`] to a fret of
+  /// the neck.
+  #[must_use]
+  pub fn absolute_fret(&self, string: usize, fret: usize) -> usize {
+    self.nut(string) + fret
+  }
+
+  /// Converts a fret of the neck to one counted from a [`String`]'s nut or
+  /// [`Capo`], or [`None`] if the fret is behind either of them.
+  #[must_use]
+  pub fn relative_fret(&self, string: usize, fret: usize) -> Option<usize> {
+    fret.checked_sub(self.nut(string))
+  }
+
+  /// Returns the frets, counted from a [`String`]'s nut or [`Capo`], which
+  /// can be played on the [`String`], or [`None`] if the [`String`] doesn't
+  /// exist or the [`Capo`] is past its last fret.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{Capo, Fretboard};
+  ///
+  /// let mut fretboard = Fretboard::new(13);
+  ///
+  /// fretboard.set_capo(Some(Capo::new(5))).unwrap();
+  ///
+  /// assert_eq!(fretboard.playable_frets(0), Some(0..=7));
+  /// assert_eq!(fretboard.playable_frets(6), None);
+  /// ```
+  #[must_use]
+  pub fn playable_frets(&self, string: usize) -> Option<RangeInclusive<usize>> {
+    let last = self.strings.get(string)?.neck_frets().end.checked_sub(1)?;
+
+    self.relative_fret(string, last).map(|last| 0..=last)
+  }
+
+  /// Returns the indices of the [`String`]s which span a fret of the neck and
+  /// aren't behind the [`Capo`] there.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{string::String, Fretboard, Pitch};
+  ///
+  /// let banjo = Fretboard::new_from_strings(
+  ///   vec![
+  ///     String::new(Pitch::new("G", 4), 18).with_start_fret(5),
+  ///     String::new(Pitch::new("D", 3), 23),
+  ///     String::new(Pitch::new("G", 3), 23),
+  ///     String::new(Pitch::new("B", 3), 23),
+  ///     String::new(Pitch::new("D", 4), 23),
+  ///   ],
+  ///   23,
+  /// );
+  ///
+  /// assert_eq!(banjo.strings_at(3), [1, 2, 3, 4]);
+  /// assert_eq!(banjo.strings_at(7), [0, 1, 2, 3, 4]);
+  /// assert_eq!(banjo.pitch_at(0, 2).unwrap().to_string(), "A4");
+  /// ```
+  #[must_use]
+  pub fn strings_at(&self, fret: usize) -> Vec<usize> {
+    (0..self.strings.len())
+      .filter(|&string| {
+        self.strings[string].spans(fret) && fret >= self.nut(string)
+      })
+      .collect()
+  }
+
+  /// Returns the sounding [`Pitch`] of a fret, counted from a [`String`]'s
+  /// nut or [`Capo`], on the [`String`].
+  #[must_use]
+  pub fn pitch_at(&self, string: usize, fret: usize) -> Option<&Pitch> {
+    self
+      .strings
+      .get(string)?
+      .fret_at(self.absolute_fret(string, fret))
+  }
+
+  /// Returns the sounding [`Pitch`] of a continuous position on a
+  /// [`String`], given as a fractional fret counted from its nut or
+  /// [`Capo`], for fretless instruments, slides, and bowed strings; see
+  /// [`String::pitch_at_position`].
+  ///
+  /// Returns [`None`] if the [`String`] doesn't exist or the position is
+  /// outside of its [`Fretboard::playable_frets`].
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{Fretboard, Tuning};
+  ///
+  /// let violin =
+  ///   Fretboard::new_from_tuning(&Tuning::named("Violin").unwrap(), 25);
+  ///
+  /// assert_eq!(violin.pitch_at_position(2, 2.).unwrap().to_string(), "B4");
+  /// assert_eq!(
+  ///   violin.pitch_at_position(2, 1.9).unwrap().to_string(),
+  ///   "B4 -10.0¢"
+  /// );
+  /// assert_eq!(
+  ///   violin.positions_of_frequency(440.),
+  ///   [(0, 14.), (1, 7.), (2, 0.)]
+  /// );
+  /// ```
+  #[must_use]
+  pub fn pitch_at_position(
+    &self,
+    string: usize,
+    position: f64,
+  ) -> Option<Pitch> {
+    self
+      .position_on_string(string, position)
+      .map(|(string, position)| string.pitch_at_position(position))
+  }
+
+  /// Returns the continuous position, as a fractional fret counted from a
+  /// [`String`]'s nut or [`Capo`], which sounds a [`Frequency`] on the
+  /// [`String`], or [`None`] if it can't be played there.
+  #[must_use]
+  pub fn position_of_frequency(
+    &self,
+    string: usize,
+    frequency: Frequency,
+  ) -> Option<f64> {
+    let string_nut = self.nut(string) - self.strings.get(string)?.start_fret();
+    let position = self.strings[string].position_of_frequency(frequency)?
+      - string_nut as f64;
+
+    (position > -1e-9)
+      .then(|| position.max(0.))
+      .filter(|&position| self.position_on_string(string, position).is_some())
+  }
+
+  /// Returns every `(string, position)` on the [`Fretboard`] which sounds a
+  /// [`Frequency`], as continuous positions; see
+  /// [`Fretboard::position_of_frequency`].
+  #[must_use]
+  pub fn positions_of_frequency(
+    &self,
+    frequency: Frequency,
+  ) -> Vec<(usize, f64)> {
+    (0..self.strings.len())
+      .filter_map(|string| {
+        self
+          .position_of_frequency(string, frequency)
+          .map(|position| (string, position))
+      })
+      .collect()
+  }
+
+  /// Returns a [`String`] and the position on it, counted from its own nut,
+  /// of a continuous position counted from its nut or [`Capo`], if the
+  /// position is playable.
+  fn position_on_string(
+    &self,
+    string: usize,
+    position: f64,
+  ) -> Option<(&String, f64)> {
+    let last = *self.playable_frets(string)?.end() as f64;
+
+    (0. ..=last).contains(&position).then(|| {
+      let string_nut = self.nut(string) - self.strings[string].start_fret();
+
+      (&self.strings[string], string_nut as f64 + position)
+    })
+  }
+
+  /// Returns the [`Pitch`]es of a shape, given as one optional fret per
+  /// [`String`] in the same order as [`Fretboard::strings`]; [`None`] is a
+  /// muted [`String`].
+  ///
+  /// Frets are counted from each [`String`]'s nut, or from the [`Capo`] on
+  /// the [`String`]s which it covers, and frets which don't exist on their
+  /// [`String`] are skipped.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{Capo, Fretboard};
+  ///
+  /// let mut fretboard = Fretboard::new(13);
+  ///
+  /// fretboard.set_capo(Some(Capo::new(3))).unwrap();
+  ///
+  /// let g = fretboard.pitches_at(&[Some(0), Some(2), Some(2), Some(0)]);
+  ///
+  /// assert_eq!(g[0].to_string(), "G2");
+  /// assert_eq!(g[1].to_string(), "D3");
+  /// ```
+  #[must_use]
+  pub fn pitches_at(&self, shape: &[Option<usize>]) -> Vec<Pitch> {
+    shape
+      .iter()
+      .enumerate()
+      .filter_map(|(string, fret)| {
+        fret.and_then(|fret| self.pitch_at(string, fret))
+      })
+      .cloned()
+      .collect()
+  }
+
+  /// Names a shape played on the [`Fretboard`]; see [`Fretboard::pitches_at`]
+  /// and [`Chord::identify`].
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// let fretboard = guitar::Fretboard::new(13);
+  /// let matches =
+  ///   fretboard.identify(&[None, Some(0), Some(2), Some(2), Some(1), Some(0)]);
+  ///
+  /// assert_eq!(matches[0].chord().to_string(), "Am");
+  /// ```
+  #[must_use]
+  pub fn identify(&self, shape: &[Option<usize>]) -> Vec<ChordMatch> {
+    Chord::identify(&self.pitches_at(shape))
+  }
+
+  /// Returns every `(string, fret)` position on the [`Fretboard`] which
+  /// sounds a [`Pitch`], including its enharmonics, within a window of frets.
+  ///
+  /// Strings are indexed in the same order as [`Fretboard::strings`], and
+  /// frets and the window are counted from each [`String`]'s nut or
+  /// [`Capo`], so fret 0 is the open string and frets behind the [`Capo`] are
+  /// never returned.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// let fretboard = guitar::Fretboard::new(13);
+  /// let b_sharp = "B#3".parse().unwrap();
+  ///
+  /// assert_eq!(
+  ///   fretboard.positions_of(&b_sharp, ..),
+  ///   [(2, 10), (3, 5), (4, 1)]
+  /// );
+  /// assert_eq!(fretboard.positions_of(&b_sharp, 5..), [(2, 10), (3, 5)]);
+  /// ```
+  #[must_use]
+  pub fn positions_of(
+    &self,
+    pitch: &Pitch,
+    window: impl RangeBounds<usize>,
+  ) -> Vec<(usize, usize)> {
+    self.positions_where(window, |fret| fret.midi_note() == pitch.midi_note())
+  }
+
+  /// Returns every `(string, fret)` position on the [`Fretboard`] which
+  /// sounds a [`PitchClass`] in any octave, including its enharmonics,
+  /// within a window of frets; see [`Fretboard::positions_of`].
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{Fretboard, PitchClass};
+  ///
+  /// let fretboard = Fretboard::new(13);
+  /// let c = PitchClass::from_name("C").unwrap();
+  ///
+  /// assert_eq!(fretboard.positions_of_class(c, ..).len(), 6);
+  /// assert_eq!(fretboard.positions_of_class(c, 0..=3), [(1, 3), (4, 1)]);
+  /// ```
+  #[must_use]
+  pub fn positions_of_class(
+    &self,
+    class: PitchClass,
+    window: impl RangeBounds<usize>,
+  ) -> Vec<(usize, usize)> {
+    self.positions_where(window, |fret| fret.pitch_class().is_enharmonic(class))
+  }
+
+  fn positions_where(
+    &self,
+    window: impl RangeBounds<usize>,
+    predicate: impl Fn(&Pitch) -> bool,
+  ) -> Vec<(usize, usize)> {
+    let predicate = &predicate;
+
+    (0..self.strings.len())
+      .flat_map(|string| {
+        self
+          .playable_frets(string)
+          .into_iter()
+          .flatten()
+          .filter(|fret| window.contains(fret))
+          .filter(move |&fret| {
+            self.pitch_at(string, fret).is_some_and(predicate)
+          })
+          .map(move |fret| (string, fret))
+      })
+      .collect()
+  }
+}
+
+impl Transpose for Fretboard {
+  /// Retunes every [`String`] of the [`Fretboard`], keeping its
+  /// [`Reference`].
+  fn transposed(&self, transposition: Transposition) -> Self {
+    Self {
+      strings: self
+        .strings
+        .iter()
+        .map(|string| string.transposed(transposition))
+        .collect(),
+      frets: self.frets,
+      reference: self.reference,
+      capo: self.capo.clone(),
+      neck: self.neck.clone(),
+    }
+  }
 }
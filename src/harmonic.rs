@@ -0,0 +1,141 @@
+// This file is part of Guitar <https://github.com/Fuwn/guitar>.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+//
+// Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
+// SPDX-License-Identifier: GPL-3.0-only
+
+#![allow(clippy::cast_precision_loss)]
+
+use crate::{
+  pitch::Pitch,
+  temperament::{EqualTemperament, Temperament},
+  unit::Cent,
+};
+
+/// The highest partial which [`String::harmonics`] returns.
+///
+/// [`String::harmonics`]: crate::string::String::harmonics
+pub const HIGHEST_PARTIAL: usize = 16;
+
+/// A harmonic of a [`String`](crate::string::String).
+///
+/// It is sounded by lightly touching one of its nodes while the string is
+/// open (a natural harmonic) or fretted (an artificial or pinch harmonic).
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::{string::String, Pitch};
+///
+/// let string = String::new(Pitch::new("E", 2), 25);
+/// let seventh = string.harmonic(7).unwrap();
+///
+/// assert_eq!(seventh.pitch().to_string(), "D5 -31.2¢");
+/// assert!((seventh.node() - 2.67).abs() < 0.01);
+/// assert!((seventh.cents_from_equal_temperament() + 31.17).abs() < 0.01);
+/// ```
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct Harmonic<T = EqualTemperament> {
+  partial: usize,
+  fret: usize,
+  pitch: Pitch<T>,
+}
+
+impl<T: Temperament> Harmonic<T> {
+  /// Creates a new [`Harmonic`] of a partial above a fretted [`Pitch`], where
+  /// fret 0 is the open string.
+  #[must_use]
+  pub fn new(fretted: &Pitch<T>, fret: usize, partial: usize) -> Self {
+    let mut pitch = fretted.clone();
+
+    pitch.set_frequency(fretted.frequency() * partial as f64);
+
+    Self {
+      partial,
+      fret,
+      pitch,
+    }
+  }
+
+  /// Returns the partial of the [`Harmonic`], where 1 is the fundamental.
+  #[must_use]
+  pub const fn partial(&self) -> usize { self.partial }
+
+  /// Returns the fret which is held down, where 0 is a natural harmonic.
+  #[must_use]
+  pub const fn fret(&self) -> usize { self.fret }
+
+  /// Returns whether the [`Harmonic`] is played on the open string.
+  #[must_use]
+  pub const fn is_natural(&self) -> bool { self.fret == 0 }
+
+  /// Returns the sounding [`Pitch`] of the [`Harmonic`], whose [`Cent`]
+  /// offset is its deviation from the [`Pitch`]'s [`Temperament`].
+  #[must_use]
+  pub const fn pitch(&self) -> &Pitch<T> { &self.pitch }
+
+  /// Returns the node nearest the nut, as a possibly fractional fret.
+  #[must_use]
+  pub fn node(&self) -> f64 { self.node_at(1) }
+
+  /// Returns every node which sounds the [`Harmonic`] rather than a lower
+  /// partial, from the nut towards the bridge, as possibly fractional frets;
+  /// nodes past the end of the fretboard are where pinch harmonics are
+  /// picked.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{string::String, Pitch};
+  ///
+  /// let string = String::new(Pitch::new("G", 3), 25);
+  /// let third = string.artificial_harmonic(2, 3).unwrap();
+  ///
+  /// assert_eq!(third.pitch().to_string(), "E5 +2.0¢");
+  /// assert!((third.nodes()[0] - 9.02).abs() < 0.01);
+  /// assert!((third.nodes()[1] - 21.02).abs() < 0.01);
+  /// ```
+  #[must_use]
+  pub fn nodes(&self) -> Vec<f64> {
+    (1..self.partial)
+      .filter(|&node| greatest_common_divisor(node, self.partial) == 1)
+      .map(|node| self.node_at(node))
+      .collect()
+  }
+
+  /// Returns the deviation of the [`Harmonic`] from the nearest
+  /// equal-tempered note above the fretted note, in [`Cent`]s.
+  #[must_use]
+  pub fn cents_from_equal_temperament(&self) -> Cent {
+    let cents = 1200. * (self.partial as f64).log2();
+
+    (cents / 100.).round().mul_add(-100., cents)
+  }
+
+  /// Returns the point `node / partial` of the way from the held fret to the
+  /// bridge, as a fret.
+  fn node_at(&self, node: usize) -> f64 {
+    (1. - node as f64 / self.partial as f64)
+      .log2()
+      .mul_add(-12., self.fret as f64)
+  }
+}
+
+const fn greatest_common_divisor(a: usize, b: usize) -> usize {
+  if b == 0 {
+    a
+  } else {
+    greatest_common_divisor(b, a % b)
+  }
+}
@@ -0,0 +1,425 @@
+// This file is part of Guitar <https://github.com/Fuwn/guitar>.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+//
+// Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
+// SPDX-License-Identifier: GPL-3.0-only
+
+#![allow(
+  clippy::cast_possible_truncation,
+  clippy::cast_possible_wrap,
+  clippy::cast_sign_loss
+)]
+
+use std::{
+  fmt,
+  ops::{Add, Sub},
+  str::FromStr,
+};
+
+use crate::{
+  accidental::Accidental,
+  convert,
+  error::IntervalError,
+  letter::Letter,
+  pitch::Pitch,
+  pitch_class::PitchClass,
+  temperament::Temperament,
+  unit::{Cent, IntervalNumber, Octave, Semitone},
+};
+
+/// The quality of an [`Interval`]; diminished and augmented qualities carry
+/// how many times they are diminished or augmented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Quality {
+  Diminished(u8),
+  Minor,
+  Perfect,
+  Major,
+  Augmented(u8),
+}
+
+impl fmt::Display for Quality {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Diminished(count) => write!(f, "{}", "d".repeat(*count as usize)),
+      Self::Minor => write!(f, "m"),
+      Self::Perfect => write!(f, "P"),
+      Self::Major => write!(f, "M"),
+      Self::Augmented(count) => write!(f, "{}", "A".repeat(*count as usize)),
+    }
+  }
+}
+
+/// A spelled distance between two [`Pitch`]es, made up of a number (the count
+/// of letters spanned, starting from 1) and a size in [`Semitone`]s.
+///
+/// Unlike a bare number of [`Semitone`]s, an [`Interval`] keeps track of
+/// spelling, so an augmented fourth and a diminished fifth stay distinct.
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::{Interval, Pitch};
+///
+/// let third = "m3".parse::<Interval>().unwrap();
+///
+/// assert_eq!(third.semitones(), 3);
+/// assert_eq!((Pitch::new("D", 4) + third).to_string(), "F4");
+/// assert_eq!(
+///   Pitch::new("G", 4) - Pitch::new("C#", 4),
+///   Interval::DIMINISHED_FIFTH
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+  number: IntervalNumber,
+  semitones: Semitone,
+}
+
+impl Interval {
+  pub const AUGMENTED_FIFTH: Self = Self::from_parts(5, 8);
+  pub const AUGMENTED_FOURTH: Self = Self::from_parts(4, 6);
+  pub const DIMINISHED_FIFTH: Self = Self::from_parts(5, 6);
+  pub const DIMINISHED_SEVENTH: Self = Self::from_parts(7, 9);
+  pub const MAJOR_NINTH: Self = Self::from_parts(9, 14);
+  pub const MAJOR_SECOND: Self = Self::from_parts(2, 2);
+  pub const MAJOR_SEVENTH: Self = Self::from_parts(7, 11);
+  pub const MAJOR_SIXTH: Self = Self::from_parts(6, 9);
+  pub const MAJOR_THIRD: Self = Self::from_parts(3, 4);
+  pub const MINOR_SECOND: Self = Self::from_parts(2, 1);
+  pub const MINOR_SEVENTH: Self = Self::from_parts(7, 10);
+  pub const MINOR_SIXTH: Self = Self::from_parts(6, 8);
+  pub const MINOR_THIRD: Self = Self::from_parts(3, 3);
+  pub const PERFECT_FIFTH: Self = Self::from_parts(5, 7);
+  pub const PERFECT_FOURTH: Self = Self::from_parts(4, 5);
+  pub const PERFECT_OCTAVE: Self = Self::from_parts(8, 12);
+  pub const PERFECT_UNISON: Self = Self::from_parts(1, 0);
+
+  /// Creates a new [`Interval`] from a [`Quality`] and a number, if the
+  /// combination exists (e.g., there is no perfect third, major fifth, or
+  /// zero-times diminished interval).
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{interval::Quality, Interval};
+  ///
+  /// assert_eq!(
+  ///   Interval::new(Quality::Major, 3),
+  ///   Some(Interval::MAJOR_THIRD),
+  /// );
+  /// assert_eq!(Interval::new(Quality::Perfect, 3), None);
+  /// ```
+  #[must_use]
+  pub fn new(quality: Quality, number: IntervalNumber) -> Option<Self> {
+    if number < 1 {
+      return None;
+    }
+
+    let perfect = Self::is_perfect_number(number);
+    let deviation = match (quality, perfect) {
+      (Quality::Perfect | Quality::Major, _)
+        if (quality == Quality::Perfect) != perfect =>
+        return None,
+      (Quality::Minor, true)
+      | (Quality::Diminished(0) | Quality::Augmented(0), _) => return None,
+      (Quality::Perfect | Quality::Major, _) => 0,
+      (Quality::Minor, false) => -1,
+      (Quality::Diminished(count), _) =>
+        -Semitone::from(count) - Semitone::from(!perfect),
+      (Quality::Augmented(count), _) => Semitone::from(count),
+    };
+
+    Some(Self::from_parts(
+      number,
+      Self::natural_semitones(number) + deviation,
+    ))
+  }
+
+  /// Creates a new [`Interval`] spanning two [`Letter`]s, `octaves` octaves
+  /// apart, with a given size in [`Semitone`]s.
+  #[must_use]
+  pub const fn from_letters(
+    lower: Letter,
+    upper: Letter,
+    octaves: Octave,
+    semitones: Semitone,
+  ) -> Self {
+    let steps = upper.index() as IntervalNumber
+      - lower.index() as IntervalNumber
+      + octaves * 7;
+
+    Self::from_parts(steps + 1, semitones)
+  }
+
+  const fn from_parts(number: IntervalNumber, semitones: Semitone) -> Self {
+    Self { number, semitones }
+  }
+
+  const fn is_perfect_number(number: IntervalNumber) -> bool {
+    matches!((number - 1) % 7, 0 | 3 | 4)
+  }
+
+  const fn natural_semitones(number: IntervalNumber) -> Semitone {
+    let simple = (number - 1) % 7;
+
+    Letter::from_index(simple as usize).semitones() + (number - 1) / 7 * 12
+  }
+
+  /// Returns the number of the [`Interval`], i.e., the count of letters it
+  /// spans, starting from 1 for a unison.
+  #[must_use]
+  pub const fn number(self) -> IntervalNumber { self.number }
+
+  /// Returns the [`Quality`] of the [`Interval`].
+  #[must_use]
+  pub const fn quality(self) -> Quality {
+    let deviation = self.semitones - Self::natural_semitones(self.number);
+
+    if Self::is_perfect_number(self.number) {
+      match deviation {
+        0 => Quality::Perfect,
+        ..=-1 => Quality::Diminished(-deviation as u8),
+        _ => Quality::Augmented(deviation as u8),
+      }
+    } else {
+      match deviation {
+        0 => Quality::Major,
+        -1 => Quality::Minor,
+        ..=-2 => Quality::Diminished((-deviation - 1) as u8),
+        _ => Quality::Augmented(deviation as u8),
+      }
+    }
+  }
+
+  /// Returns the size of the [`Interval`] in [`Semitone`]s.
+  #[must_use]
+  pub const fn semitones(self) -> Semitone { self.semitones }
+
+  /// Returns the size of the [`Interval`] in [`Cent`]s, assuming equal
+  /// temperament.
+  #[must_use]
+  pub fn cents(self) -> Cent { convert::semitones_to_cents(self.semitones) }
+
+  /// Returns whether the [`Interval`] is larger than an octave.
+  #[must_use]
+  pub const fn is_compound(self) -> bool { self.number > 8 }
+
+  /// Returns the [`Interval`] reduced to within an octave, e.g., a major
+  /// ninth becomes a major second; an octave stays an octave.
+  #[must_use]
+  pub const fn simple(self) -> Self {
+    if self.is_compound() {
+      let octaves = (self.number - 2) / 7;
+
+      Self::from_parts(self.number - octaves * 7, self.semitones - octaves * 12)
+    } else {
+      self
+    }
+  }
+
+  /// Returns the [`Interval`] widened by a number of octaves, e.g., a major
+  /// second widened by one octave becomes a major ninth.
+  #[must_use]
+  pub const fn compound(self, octaves: Octave) -> Self {
+    Self::from_parts(self.number + octaves * 7, self.semitones + octaves * 12)
+  }
+
+  /// Returns the inversion of the [`Interval`]; compound intervals are
+  /// reduced to simple intervals before being inverted.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::Interval;
+  ///
+  /// assert_eq!(Interval::MAJOR_THIRD.inversion(), Interval::MINOR_SIXTH);
+  /// assert_eq!(
+  ///   Interval::AUGMENTED_FOURTH.inversion(),
+  ///   Interval::DIMINISHED_FIFTH
+  /// );
+  /// assert_eq!(Interval::MAJOR_NINTH.inversion(), Interval::MINOR_SEVENTH);
+  /// ```
+  #[must_use]
+  pub const fn inversion(self) -> Self {
+    let simple = self.simple();
+
+    Self::from_parts(9 - simple.number, 12 - simple.semitones)
+  }
+
+  /// Returns the [`Interval`] between two [`Pitch`]es, measured upwards from
+  /// the lower to the higher one.
+  #[must_use]
+  pub const fn between<T: Temperament>(
+    first: &Pitch<T>,
+    second: &Pitch<T>,
+  ) -> Self {
+    let (lower, upper) = if Self::steps(first) <= Self::steps(second) {
+      (first, second)
+    } else {
+      (second, first)
+    };
+
+    Self::from_parts(
+      Self::steps(upper) - Self::steps(lower) + 1,
+      Self::position(upper) - Self::position(lower),
+    )
+  }
+
+  const fn steps<T: Temperament>(pitch: &Pitch<T>) -> IntervalNumber {
+    pitch.octave() * 7 + pitch.letter().index() as IntervalNumber
+  }
+
+  const fn position<T: Temperament>(pitch: &Pitch<T>) -> Semitone {
+    convert::pitch_and_octave_to_semitones(pitch.pitch_class(), pitch.octave())
+  }
+
+  /// Transposes a [`Pitch`] by the [`Interval`] upwards or downwards,
+  /// keeping the correct letter spelling.
+  ///
+  /// When the correct spelling would need more than a double accidental, the
+  /// [`Pitch`] is respelled with sharps.
+  fn transpose<T: Temperament>(
+    self,
+    pitch: &Pitch<T>,
+    upwards: bool,
+  ) -> Pitch<T> {
+    let sign = if upwards { 1 } else { -1 };
+    let steps = Self::steps(pitch) + sign * (self.number - 1);
+    let octave = steps.div_euclid(7);
+    let letter = Letter::from_index(steps.rem_euclid(7) as usize);
+    let target = Self::position(pitch) + sign * self.semitones;
+    let natural = convert::pitch_and_octave_to_semitones(
+      PitchClass::natural(letter),
+      octave,
+    );
+    let (class, octave) = Accidental::from_semitones(target - natural)
+      .map_or_else(
+        || {
+          let nearest = PitchClass::from_semitones(target + 9);
+
+          (nearest, (target + 9 + 48).div_euclid(12))
+        },
+        |accidental| (PitchClass::new(letter, accidental), octave),
+      );
+
+    let mut transposed = pitch.clone();
+
+    transposed.set_pitch_class(class);
+    transposed.set_octave(octave);
+
+    transposed
+  }
+}
+
+impl fmt::Display for Interval {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}{}", self.quality(), self.number)
+  }
+}
+
+impl FromStr for Interval {
+  type Err = IntervalError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let split = s
+      .find(|character: char| character.is_ascii_digit())
+      .unwrap_or(s.len());
+    let (quality, number) = s.split_at(split);
+    let count = u8::try_from(quality.len())
+      .map_err(|_| IntervalError::InvalidQuality(quality.to_string()))?;
+    let quality = match quality {
+      "P" => Quality::Perfect,
+      "M" => Quality::Major,
+      "m" => Quality::Minor,
+      _ if count > 0 && quality.chars().all(|c| c == 'd') =>
+        Quality::Diminished(count),
+      _ if count > 0 && quality.chars().all(|c| c == 'A') =>
+        Quality::Augmented(count),
+      _ => return Err(IntervalError::InvalidQuality(quality.to_string())),
+    };
+    let number = number
+      .parse::<IntervalNumber>()
+      .ok()
+      .filter(|number| *number >= 1)
+      .ok_or_else(|| IntervalError::InvalidNumber(number.to_string()))?;
+
+    Self::new(quality, number)
+      .ok_or(IntervalError::ImpossibleQuality(quality, number))
+  }
+}
+
+impl Add for Interval {
+  type Output = Self;
+
+  fn add(self, other: Self) -> Self::Output {
+    Self::from_parts(
+      self.number + other.number - 1,
+      self.semitones + other.semitones,
+    )
+  }
+}
+
+impl Sub for Interval {
+  type Output = Self;
+
+  /// Finds the [`Interval`] which, added to `other`, makes `self`; `other`
+  /// shouldn't have a larger number than `self`.
+  fn sub(self, other: Self) -> Self::Output {
+    Self::from_parts(
+      self.number - other.number + 1,
+      self.semitones - other.semitones,
+    )
+  }
+}
+
+impl<T: Temperament> Add<Interval> for &Pitch<T> {
+  type Output = Pitch<T>;
+
+  fn add(self, interval: Interval) -> Self::Output {
+    interval.transpose(self, true)
+  }
+}
+
+impl<T: Temperament> Add<Interval> for Pitch<T> {
+  type Output = Self;
+
+  fn add(self, interval: Interval) -> Self::Output { &self + interval }
+}
+
+impl<T: Temperament> Sub<Interval> for &Pitch<T> {
+  type Output = Pitch<T>;
+
+  fn sub(self, interval: Interval) -> Self::Output {
+    interval.transpose(self, false)
+  }
+}
+
+impl<T: Temperament> Sub<Interval> for Pitch<T> {
+  type Output = Self;
+
+  fn sub(self, interval: Interval) -> Self::Output { &self - interval }
+}
+
+impl<T: Temperament> Sub for &Pitch<T> {
+  type Output = Interval;
+
+  fn sub(self, other: Self) -> Self::Output { Interval::between(other, self) }
+}
+
+impl<T: Temperament> Sub for Pitch<T> {
+  type Output = Interval;
+
+  fn sub(self, other: Self) -> Self::Output { &self - &other }
+}
@@ -0,0 +1,295 @@
+// This file is part of Guitar <https://github.com/Fuwn/guitar>.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+//
+// Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
+// SPDX-License-Identifier: GPL-3.0-only
+
+#![allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+
+use std::fmt;
+
+use crate::{
+  pitch::Pitch,
+  pitch_class::PitchClass,
+  scale::{Scale, ScaleKind},
+  temperament::Temperament,
+  unit::Octave,
+};
+
+/// A key: a tonic [`PitchClass`] and a diatonic mode, such as D major, F#
+/// minor, or E Dorian.
+///
+/// A [`Key`] knows its key signature and uses it to spell pitches in context.
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::{Key, Pitch, PitchClass};
+///
+/// let d_major = Key::major(PitchClass::from_name("D").unwrap());
+///
+/// assert_eq!(d_major.sharps(), 2);
+/// assert_eq!(d_major.spell(&Pitch::new("Gb", 4)).to_string(), "F#4");
+/// assert_eq!(d_major.relative().to_string(), "B minor");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key {
+  tonic: PitchClass,
+  mode: ScaleKind,
+}
+
+impl Key {
+  /// Creates a new [`Key`] from a tonic and one of the seven diatonic modes,
+  /// from [`ScaleKind::Ionian`] to [`ScaleKind::Locrian`].
+  #[must_use]
+  pub const fn new(tonic: PitchClass, mode: ScaleKind) -> Option<Self> {
+    if Self::mode_fifths(mode).is_some() {
+      Some(Self { tonic, mode })
+    } else {
+      None
+    }
+  }
+
+  /// Creates a new major [`Key`].
+  #[must_use]
+  pub const fn major(tonic: PitchClass) -> Self {
+    Self {
+      tonic,
+      mode: ScaleKind::MAJOR,
+    }
+  }
+
+  /// Creates a new (natural) minor [`Key`].
+  #[must_use]
+  pub const fn minor(tonic: PitchClass) -> Self {
+    Self {
+      tonic,
+      mode: ScaleKind::MINOR,
+    }
+  }
+
+  /// Returns how many fifths the tonic of a diatonic mode is above the tonic
+  /// of its parent major scale.
+  const fn mode_fifths(mode: ScaleKind) -> Option<i64> {
+    match mode {
+      ScaleKind::Lydian => Some(-1),
+      ScaleKind::Ionian => Some(0),
+      ScaleKind::Mixolydian => Some(1),
+      ScaleKind::Dorian => Some(2),
+      ScaleKind::Aeolian => Some(3),
+      ScaleKind::Phrygian => Some(4),
+      ScaleKind::Locrian => Some(5),
+      _ => None,
+    }
+  }
+
+  /// Returns the tonic of the [`Key`].
+  #[must_use]
+  pub const fn tonic(self) -> PitchClass { self.tonic }
+
+  /// Returns the mode of the [`Key`].
+  #[must_use]
+  pub const fn mode(self) -> ScaleKind { self.mode }
+
+  /// Returns whether the [`Key`] has a major third above its tonic.
+  #[must_use]
+  pub const fn is_major(self) -> bool {
+    matches!(
+      self.mode,
+      ScaleKind::Ionian | ScaleKind::Lydian | ScaleKind::Mixolydian
+    )
+  }
+
+  /// Returns the key signature of the [`Key`] as a signed count; positive
+  /// counts are sharps and negative counts are flats.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{Key, PitchClass};
+  ///
+  /// let key = |name| Key::minor(PitchClass::from_name(name).unwrap());
+  ///
+  /// assert_eq!(key("C#").signature(), 4);
+  /// assert_eq!(key("Bb").signature(), -5);
+  /// ```
+  #[must_use]
+  pub const fn signature(self) -> i64 {
+    let offset = match Self::mode_fifths(self.mode) {
+      Some(offset) => offset,
+      None => 0,
+    };
+
+    self.tonic.fifths() - offset
+  }
+
+  /// Returns the number of sharps in the key signature.
+  #[must_use]
+  pub const fn sharps(self) -> usize {
+    if self.signature() > 0 {
+      self.signature() as usize
+    } else {
+      0
+    }
+  }
+
+  /// Returns the number of flats in the key signature.
+  #[must_use]
+  pub const fn flats(self) -> usize {
+    if self.signature() < 0 {
+      -self.signature() as usize
+    } else {
+      0
+    }
+  }
+
+  /// Returns the altered [`PitchClass`]es of the key signature, in the order
+  /// they are written (F#, C#, G#, ... or Bb, Eb, Ab, ...).
+  #[must_use]
+  pub fn accidentals(self) -> Vec<PitchClass> {
+    let signature = self.signature();
+    let fifths = if signature >= 0 {
+      (6..6 + signature).collect::<Vec<_>>()
+    } else {
+      (signature - 1..-1).rev().collect()
+    };
+
+    fifths
+      .into_iter()
+      .filter_map(PitchClass::from_fifths)
+      .collect()
+  }
+
+  /// Returns the spelled [`PitchClass`]es of the [`Key`], starting from its
+  /// tonic.
+  #[must_use]
+  pub fn pitch_classes(self) -> Vec<PitchClass> {
+    self.scale(4).pitch_classes()
+  }
+
+  /// Returns the [`Scale`] of the [`Key`], rooted at an [`Octave`].
+  #[must_use]
+  pub fn scale(self, octave: Octave) -> Scale {
+    Scale::new(Pitch::new_from_pitch_class(self.tonic, octave), self.mode)
+  }
+
+  /// Returns the relative [`Key`]: the relative minor of a major key, or the
+  /// relative major of any other mode.
+  #[must_use]
+  pub fn relative(self) -> Self {
+    let signature = self.signature();
+
+    if self.mode == ScaleKind::MAJOR {
+      Self::minor(PitchClass::from_fifths(signature + 3).unwrap_or(self.tonic))
+    } else {
+      Self::major(PitchClass::from_fifths(signature).unwrap_or(self.tonic))
+    }
+  }
+
+  /// Returns the parallel [`Key`]: the minor key on the same tonic for keys
+  /// with a major third, or the major key on the same tonic otherwise.
+  #[must_use]
+  pub const fn parallel(self) -> Self {
+    if self.is_major() {
+      Self::minor(self.tonic)
+    } else {
+      Self::major(self.tonic)
+    }
+  }
+
+  /// Returns the neighbouring [`Key`] a fifth above on the circle of fifths,
+  /// in the same mode.
+  ///
+  /// Tonics are respelled enharmonically rather than exceeding seven sharps
+  /// or flats.
+  #[must_use]
+  pub fn dominant(self) -> Self { self.move_on_circle(1) }
+
+  /// Returns the neighbouring [`Key`] a fifth below on the circle of fifths,
+  /// in the same mode.
+  ///
+  /// Tonics are respelled enharmonically rather than exceeding seven sharps
+  /// or flats.
+  #[must_use]
+  pub fn subdominant(self) -> Self { self.move_on_circle(-1) }
+
+  fn move_on_circle(self, fifths: i64) -> Self {
+    let mut tonic = self.tonic.fifths() + fifths;
+    let signature = self.signature() + fifths;
+
+    if signature > 7 {
+      tonic -= 12;
+    } else if signature < -7 {
+      tonic += 12;
+    }
+
+    Self {
+      tonic: PitchClass::from_fifths(tonic).unwrap_or(self.tonic),
+      mode: self.mode,
+    }
+  }
+
+  /// Spells a [`PitchClass`] in the context of the [`Key`].
+  ///
+  /// Diatonic pitches take their spelling from the key, and chromatic pitches
+  /// take the spelling closest to the key on the line of fifths, so sharp
+  /// keys favour sharps and flat keys favour flats.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{Key, PitchClass};
+  ///
+  /// let name = |name| PitchClass::from_name(name).unwrap();
+  /// let d_flat = Key::major(name("Db"));
+  ///
+  /// assert_eq!(d_flat.spell_class(name("F#")).to_string(), "Gb");
+  /// assert_eq!(d_flat.spell_class(name("B")).to_string(), "Cb");
+  /// assert_eq!(
+  ///   Key::minor(name("A")).spell_class(name("Ab")).to_string(),
+  ///   "G#"
+  /// );
+  /// ```
+  #[must_use]
+  pub fn spell_class(self, class: PitchClass) -> PitchClass {
+    let centre = self.signature() + 2;
+
+    class
+      .enharmonics()
+      .into_iter()
+      .min_by_key(|spelling| {
+        ((spelling.fifths() - centre).abs(), -spelling.fifths())
+      })
+      .unwrap_or(class)
+  }
+
+  /// Spells a [`Pitch`] in the context of the [`Key`], keeping its sound; see
+  /// [`Key::spell_class`].
+  #[must_use]
+  pub fn spell<T: Temperament>(self, pitch: &Pitch<T>) -> Pitch<T> {
+    pitch
+      .respell(self.spell_class(pitch.pitch_class()))
+      .unwrap_or_else(|| pitch.clone())
+  }
+}
+
+impl fmt::Display for Key {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.mode {
+      ScaleKind::Ionian => write!(f, "{} major", self.tonic),
+      ScaleKind::Aeolian => write!(f, "{} minor", self.tonic),
+      mode => write!(f, "{} {mode}", self.tonic),
+    }
+  }
+}
@@ -0,0 +1,122 @@
+// This file is part of Guitar <https://github.com/Fuwn/guitar>.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+//
+// Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::fmt;
+
+use crate::unit::Semitone;
+
+/// One of the seven natural note letters, independent of any
+/// [`Accidental`](crate::Accidental).
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::Letter;
+///
+/// assert_eq!(Letter::from_char('e'), Some(Letter::E));
+/// assert_eq!(Letter::B.offset(1), Letter::C);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Letter {
+  C,
+  D,
+  E,
+  F,
+  G,
+  A,
+  B,
+}
+
+impl Letter {
+  /// Every [`Letter`], in ascending order starting from C.
+  pub const ALL: [Self; 7] = [
+    Self::C,
+    Self::D,
+    Self::E,
+    Self::F,
+    Self::G,
+    Self::A,
+    Self::B,
+  ];
+
+  /// Returns the [`Letter`] at `index` steps above C, wrapping every seven
+  /// steps.
+  #[must_use]
+  pub const fn from_index(index: usize) -> Self { Self::ALL[index % 7] }
+
+  /// Returns the [`Letter`] represented by a character, ignoring case.
+  #[must_use]
+  pub const fn from_char(character: char) -> Option<Self> {
+    match character.to_ascii_uppercase() {
+      'C' => Some(Self::C),
+      'D' => Some(Self::D),
+      'E' => Some(Self::E),
+      'F' => Some(Self::F),
+      'G' => Some(Self::G),
+      'A' => Some(Self::A),
+      'B' => Some(Self::B),
+      _ => None,
+    }
+  }
+
+  /// Returns the number of steps that the [`Letter`] is above C.
+  #[must_use]
+  pub const fn index(self) -> usize { self as usize }
+
+  /// Returns the number of [`Semitone`]s that the natural [`Letter`] is above
+  /// C.
+  #[must_use]
+  pub const fn semitones(self) -> Semitone {
+    match self {
+      Self::C => 0,
+      Self::D => 2,
+      Self::E => 4,
+      Self::F => 5,
+      Self::G => 7,
+      Self::A => 9,
+      Self::B => 11,
+    }
+  }
+
+  /// Returns the [`Letter`] which is `steps` letters away, wrapping around
+  /// the octave in either direction.
+  #[must_use]
+  #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+  pub const fn offset(self, steps: i64) -> Self {
+    Self::from_index((self as i64 + steps).rem_euclid(7) as usize)
+  }
+
+  /// Returns the uppercase character of the [`Letter`].
+  #[must_use]
+  pub const fn to_char(self) -> char {
+    match self {
+      Self::C => 'C',
+      Self::D => 'D',
+      Self::E => 'E',
+      Self::F => 'F',
+      Self::G => 'G',
+      Self::A => 'A',
+      Self::B => 'B',
+    }
+  }
+}
+
+impl fmt::Display for Letter {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.to_char())
+  }
+}
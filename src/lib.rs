@@ -30,16 +30,54 @@
 
 // #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 
+pub mod accidental;
+pub mod analysis;
+pub mod capo;
+pub mod chord;
 pub mod convert;
+pub mod error;
 pub mod fretboard;
+pub mod harmonic;
+pub mod interval;
+pub mod key;
+pub mod letter;
+pub mod neck;
 pub mod note;
 pub mod notes;
 pub mod pitch;
+pub mod pitch_class;
+pub mod pitch_class_set;
+pub mod reference;
+pub mod scala;
+pub mod scale;
 pub mod string;
+pub mod string_set;
+pub mod temperament;
+pub mod tension;
+pub mod transpose;
+pub mod tuning;
 pub mod unit;
 pub mod utility;
 
+pub use accidental::Accidental;
+pub use capo::Capo;
+pub use chord::Chord;
+pub use error::{
+  ChordError, FretboardError, IntervalError, PitchError, ScalaError,
+  TuningError,
+};
 pub use fretboard::Fretboard;
+pub use interval::Interval;
+pub use key::Key;
+pub use letter::Letter;
+pub use neck::Neck;
 pub use note::Note;
 pub use notes::NOTES;
 pub use pitch::Pitch;
+pub use pitch_class::PitchClass;
+pub use pitch_class_set::{ForteNumber, PitchClassSet};
+pub use reference::Reference;
+pub use scale::Scale;
+pub use temperament::Temperament;
+pub use transpose::Transpose;
+pub use tuning::Tuning;
@@ -0,0 +1,434 @@
+// This file is part of Guitar <https://github.com/Fuwn/guitar>.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+//
+// Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
+// SPDX-License-Identifier: GPL-3.0-only
+
+#![allow(clippy::cast_precision_loss)]
+
+use crate::{
+  fretboard::Fretboard,
+  string::String,
+  temperament::Temperament,
+  unit::{Frets, Length},
+  utility::inches_to_millimeters,
+};
+
+/// The physical layout of a fretted neck: where its frets sit along the scale
+/// length of each string, and how far apart its strings are, in millimetres.
+///
+/// Frets are placed for twelve-tone equal temperament, so each fret shortens
+/// the sounding length of the string by a factor of 2^(1/12). Each string can
+/// have its own scale length, which fans the frets out around a fret which
+/// stays perpendicular to the neck.
+///
+/// # Examples
+///
+/// ```rust
+/// let neck = guitar::Neck::new_from_inches(25.5, 22);
+///
+/// assert!((neck.scale_length() - 647.7).abs() < 1e-9);
+/// assert!((neck.fret_position(1) - 36.35).abs() < 0.01);
+/// assert!((neck.twelfth_fret() - 323.85).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Neck {
+  scale_lengths: Vec<Length>,
+  frets: Frets,
+  perpendicular_fret: usize,
+  nut_spacing: Length,
+  bridge_spacing: Length,
+}
+
+impl Neck {
+  /// The distance between the outer strings at the bridge of a typical
+  /// steel-string guitar.
+  pub const DEFAULT_BRIDGE_SPACING: Length = 52.;
+  /// The distance between the outer strings at the nut of a typical
+  /// steel-string guitar.
+  pub const DEFAULT_NUT_SPACING: Length = 35.;
+
+  /// Creates a new six-string [`Neck`] from a scale length in millimetres and
+  /// a number of [`Frets`], counting the nut as in
+  /// [`Fretboard::frets`](crate::Fretboard::frets).
+  #[must_use]
+  pub fn new(scale_length: Length, frets: Frets) -> Self {
+    Self::new_from_scale_lengths(vec![scale_length; 6], frets)
+  }
+
+  /// Creates a new six-string [`Neck`] from a scale length in inches and a
+  /// number of [`Frets`].
+  #[must_use]
+  pub fn new_from_inches(scale_length: f64, frets: Frets) -> Self {
+    Self::new(inches_to_millimeters(scale_length), frets)
+  }
+
+  /// Creates a new [`Neck`] with the same number of [`Frets`] and strings as
+  /// a [`Fretboard`], from a scale length in millimetres.
+  #[must_use]
+  pub fn new_from_fretboard(
+    scale_length: Length,
+    fretboard: &Fretboard,
+  ) -> Self {
+    Self::new_from_scale_lengths(
+      vec![scale_length; fretboard.strings().len()],
+      *fretboard.frets(),
+    )
+  }
+
+  /// Creates a new [`Neck`] from the scale length of each string in
+  /// millimetres, in the same order as
+  /// [`Fretboard::strings`](crate::Fretboard::strings), and a number of
+  /// [`Frets`].
+  #[must_use]
+  pub const fn new_from_scale_lengths(
+    scale_lengths: Vec<Length>,
+    frets: Frets,
+  ) -> Self {
+    Self {
+      scale_lengths,
+      frets,
+      perpendicular_fret: 0,
+      nut_spacing: Self::DEFAULT_NUT_SPACING,
+      bridge_spacing: Self::DEFAULT_BRIDGE_SPACING,
+    }
+  }
+
+  /// Creates a new multi-scale [`Neck`] whose scale lengths step evenly from
+  /// the first string to the last, with a fret which stays perpendicular to
+  /// the neck.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// let neck = guitar::Neck::new_multiscale(686., 648., 7, 25, 8);
+  ///
+  /// assert!(neck.is_multiscale());
+  /// assert_eq!(neck.scale_lengths()[3], 667.);
+  /// assert_eq!(neck.fret_angle(8), 0.);
+  /// assert!(neck.fret_angle(0) > 0. && neck.fret_angle(24) < 0.);
+  /// ```
+  #[must_use]
+  pub fn new_multiscale(
+    first: Length,
+    last: Length,
+    strings: usize,
+    frets: Frets,
+    perpendicular_fret: usize,
+  ) -> Self {
+    let gaps = strings.saturating_sub(1).max(1) as f64;
+    let mut neck = Self::new_from_scale_lengths(
+      (0..strings)
+        .map(|string| (last - first).mul_add(string as f64 / gaps, first))
+        .collect(),
+      frets,
+    );
+
+    neck.set_perpendicular_fret(perpendicular_fret);
+
+    neck
+  }
+
+  /// Returns the scale length of the first string of the [`Neck`], from the
+  /// nut to the bridge saddle; this is the scale length of every string
+  /// unless the [`Neck`] is multi-scale.
+  #[must_use]
+  pub fn scale_length(&self) -> Length {
+    self.scale_lengths.first().copied().unwrap_or_default()
+  }
+
+  /// Returns the scale length of each string of the [`Neck`].
+  #[must_use]
+  pub fn scale_lengths(&self) -> &[Length] { &self.scale_lengths }
+
+  /// Returns whether the strings of the [`Neck`] have different scale
+  /// lengths.
+  #[must_use]
+  pub fn is_multiscale(&self) -> bool {
+    self
+      .scale_lengths
+      .windows(2)
+      .any(|pair| (pair[0] - pair[1]).abs() > f64::EPSILON)
+  }
+
+  /// Returns the fret which stays perpendicular to a multi-scale [`Neck`].
+  #[must_use]
+  pub const fn perpendicular_fret(&self) -> usize { self.perpendicular_fret }
+
+  /// Sets the fret which stays perpendicular to a multi-scale [`Neck`].
+  pub const fn set_perpendicular_fret(&mut self, fret: usize) {
+    self.perpendicular_fret = fret;
+  }
+
+  /// Returns the number of [`Frets`] of the [`Neck`], counting the nut.
+  #[must_use]
+  pub const fn frets(&self) -> Frets { self.frets }
+
+  /// Returns the number of strings of the [`Neck`].
+  #[must_use]
+  pub const fn strings(&self) -> usize { self.scale_lengths.len() }
+
+  /// Returns the distance between the outer strings at the nut.
+  #[must_use]
+  pub const fn nut_spacing(&self) -> Length { self.nut_spacing }
+
+  /// Returns the distance between the outer strings at the bridge.
+  #[must_use]
+  pub const fn bridge_spacing(&self) -> Length { self.bridge_spacing }
+
+  /// Sets the distances between the outer strings at the nut and at the
+  /// bridge.
+  pub const fn set_string_spacing(&mut self, nut: Length, bridge: Length) {
+    self.nut_spacing = nut;
+    self.bridge_spacing = bridge;
+  }
+
+  /// Returns the distance of a fret from the nut along the first string.
+  #[must_use]
+  pub fn fret_position(&self, fret: usize) -> Length {
+    fret_position(self.scale_length(), fret as f64)
+  }
+
+  /// Returns the distance of a fret from the nut along a string, or [`None`]
+  /// if the string doesn't exist.
+  #[must_use]
+  pub fn fret_position_on(&self, string: usize, fret: usize) -> Option<Length> {
+    self
+      .scale_lengths
+      .get(string)
+      .map(|&scale_length| fret_position(scale_length, fret as f64))
+  }
+
+  /// Returns the distance from the nut along a string of a continuous
+  /// position given as a fractional fret, for fretless and slide playing, or
+  /// [`None`] if the string doesn't exist.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// let neck = guitar::Neck::new(864., 24);
+  ///
+  /// let octave = neck.distance_of_position(0, 12.).unwrap();
+  /// let position = neck.position_at_distance(0, octave).unwrap();
+  ///
+  /// assert!((octave - 432.).abs() < 1e-9);
+  /// assert!((position - 12.).abs() < 1e-9);
+  /// assert!(neck.distance_of_position(0, 2.5) > Some(neck.fret_position(2)));
+  /// ```
+  #[must_use]
+  pub fn distance_of_position(
+    &self,
+    string: usize,
+    position: f64,
+  ) -> Option<Length> {
+    self
+      .scale_lengths
+      .get(string)
+      .map(|&scale_length| fret_position(scale_length, position))
+  }
+
+  /// Returns the continuous position, as a fractional fret, of a distance
+  /// from the nut along a string, or [`None`] if the string doesn't exist or
+  /// the distance isn't between the nut and the bridge.
+  #[must_use]
+  pub fn position_at_distance(
+    &self,
+    string: usize,
+    distance: Length,
+  ) -> Option<f64> {
+    let scale_length = *self.scale_lengths.get(string)?;
+
+    (0. ..scale_length)
+      .contains(&distance)
+      .then(|| -12. * (1. - distance / scale_length).log2())
+  }
+
+  /// Returns the distance of a fret from the one before it along the first
+  /// string, or 0 for the nut.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// let neck = guitar::Neck::new(650., 20);
+  ///
+  /// assert_eq!(neck.fret_spacing(0), 0.);
+  /// assert!(neck.fret_spacing(1) > neck.fret_spacing(2));
+  /// ```
+  #[must_use]
+  pub fn fret_spacing(&self, fret: usize) -> Length {
+    fret.checked_sub(1).map_or(0., |previous| {
+      self.fret_position(fret) - self.fret_position(previous)
+    })
+  }
+
+  /// Returns the distance of every fret from the nut along the first string,
+  /// starting with the nut itself.
+  #[must_use]
+  pub fn fret_positions(&self) -> Vec<Length> {
+    (0..self.frets)
+      .map(|fret| self.fret_position(fret))
+      .collect()
+  }
+
+  /// Returns the distance from the nut of every fret of the neck which a
+  /// [`String`] spans, along the string of the [`Neck`] at an index, or
+  /// [`None`] if that string doesn't exist; see [`String::neck_frets`].
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{string::String, Neck, Pitch};
+  ///
+  /// let neck = Neck::new_multiscale(686., 648., 7, 25, 8);
+  /// let low_b = String::new(Pitch::new("B", 1), 25);
+  /// let positions = neck.string_fret_positions(0, &low_b).unwrap();
+  ///
+  /// assert!((positions[12] - 343.).abs() < 1e-9);
+  /// assert!(neck.string_fret_positions(7, &low_b).is_none());
+  /// ```
+  #[must_use]
+  pub fn string_fret_positions<T: Temperament>(
+    &self,
+    index: usize,
+    string: &String<T>,
+  ) -> Option<Vec<Length>> {
+    self.scale_lengths.get(index)?;
+
+    string
+      .neck_frets()
+      .map(|fret| self.fret_position_on(index, fret))
+      .collect()
+  }
+
+  /// Returns the length of the first string between a fret and the bridge
+  /// saddle.
+  #[must_use]
+  pub fn sounding_length(&self, fret: usize) -> Length {
+    sounding_length(self.scale_length(), fret as f64)
+  }
+
+  /// Returns where the twelfth fret should be along the first string, which
+  /// is half of its scale length.
+  #[must_use]
+  pub fn twelfth_fret(&self) -> Length { self.scale_length() / 2. }
+
+  /// Checks a measured distance between the nut and the twelfth fret
+  /// against [`Neck::twelfth_fret`], within a tolerance.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// let neck = guitar::Neck::new(648., 22);
+  ///
+  /// assert!(neck.check_twelfth_fret(324.2, 0.25));
+  /// assert!(!neck.check_twelfth_fret(325., 0.25));
+  /// ```
+  #[must_use]
+  pub fn check_twelfth_fret(
+    &self,
+    measured: Length,
+    tolerance: Length,
+  ) -> bool {
+    (measured - self.twelfth_fret()).abs() <= tolerance
+  }
+
+  /// Returns the distance between the outer strings at a distance from the
+  /// nut, tapering from the nut spacing to the bridge spacing.
+  #[must_use]
+  pub fn string_spacing_at(&self, distance: Length) -> Length {
+    (self.bridge_spacing - self.nut_spacing)
+      .mul_add(distance / self.scale_length(), self.nut_spacing)
+  }
+
+  /// Returns the offset of each string from the centre line of the [`Neck`]
+  /// at a distance from the nut, in the same order as
+  /// [`Fretboard::strings`].
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// let neck = guitar::Neck::new(650., 20);
+  /// let nut = neck.string_offsets_at(0.);
+  ///
+  /// assert_eq!(nut.len(), 6);
+  /// assert_eq!(nut[0], -17.5);
+  /// assert_eq!(nut[5], 17.5);
+  /// ```
+  #[must_use]
+  pub fn string_offsets_at(&self, distance: Length) -> Vec<Length> {
+    let spacing = self.string_spacing_at(distance);
+
+    (0..self.strings())
+      .map(|string| {
+        self
+          .lateral_fraction(string)
+          .mul_add(spacing, -spacing / 2.)
+      })
+      .collect()
+  }
+
+  /// Returns where a fret crosses each string, as a distance along the neck
+  /// from the perpendicular fret (negative towards the nut) and an offset
+  /// from the centre line, in the same order as [`Fretboard::strings`].
+  #[must_use]
+  pub fn fret_points(&self, fret: usize) -> Vec<(Length, Length)> {
+    self
+      .scale_lengths
+      .iter()
+      .enumerate()
+      .map(|(string, &scale_length)| {
+        let position = fret_position(scale_length, fret as f64);
+        let along_string = position / scale_length;
+        let spacing = (self.bridge_spacing - self.nut_spacing)
+          .mul_add(along_string, self.nut_spacing);
+
+        (
+          position
+            - fret_position(scale_length, self.perpendicular_fret as f64),
+          self
+            .lateral_fraction(string)
+            .mul_add(spacing, -spacing / 2.),
+        )
+      })
+      .collect()
+  }
+
+  /// Returns the angle of a fret from perpendicular to the neck, in degrees,
+  /// which is positive when the fret's last-string end is further from the
+  /// nut than its first-string end.
+  #[must_use]
+  pub fn fret_angle(&self, fret: usize) -> f64 {
+    let points = self.fret_points(fret);
+
+    match (points.first(), points.last()) {
+      (Some((first_along, first_across)), Some((last_along, last_across))) =>
+        (last_along - first_along)
+          .atan2(last_across - first_across)
+          .to_degrees(),
+      _ => 0.,
+    }
+  }
+
+  fn lateral_fraction(&self, string: usize) -> f64 {
+    string as f64 / self.strings().saturating_sub(1).max(1) as f64
+  }
+}
+
+fn sounding_length(scale_length: Length, fret: f64) -> Length {
+  scale_length / (fret / 12.).exp2()
+}
+
+fn fret_position(scale_length: Length, fret: f64) -> Length {
+  scale_length - sounding_length(scale_length, fret)
+}
@@ -15,7 +15,12 @@
 // Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
 // SPDX-License-Identifier: GPL-3.0-only
 
-use crate::{pitch::Pitch, unit::Octave};
+use crate::{
+  error::PitchError,
+  pitch::Pitch,
+  transpose::{Transpose, Transposition},
+  unit::Octave,
+};
 
 /// A note which only keeps track of its frequency, octave, and name; enables
 /// for a more flexible note system; including conversions, enharmonics, and
@@ -47,6 +52,24 @@ impl Note {
     Self::new_from_pitch(pitch)
   }
 
+  /// Creates a new, specific [`Note`] given a note's name and octave,
+  /// reporting an invalid name or octave as a [`PitchError`].
+  ///
+  /// # Errors
+  ///
+  /// Returns a [`PitchError`] under the same conditions as
+  /// [`Pitch::try_new`].
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// assert!(guitar::Note::try_new("A", 4).is_ok());
+  /// assert!(guitar::Note::try_new("A", 42).is_err());
+  /// ```
+  pub fn try_new(pitch: &str, octave: Octave) -> Result<Self, PitchError> {
+    Pitch::try_new(pitch, octave).map(Self::new_from_pitch)
+  }
+
   /// Creates a new, specific [`Note`] given a frequency and a [`Pitch`].
   ///
   /// This is an internal command which is made obsolete by the other,
@@ -55,8 +78,23 @@ impl Note {
   pub const fn new_from_pitch(pitch: Pitch) -> Self { Self { pitch } }
 
   /// Returns the [`Pitch`] of the [`Note`] for further manipulation.
-  pub fn pitch(&mut self) -> &Pitch { &self.pitch }
+  #[must_use]
+  pub const fn pitch(&self) -> &Pitch { &self.pitch }
 
   /// Returns the [`Pitch`] of the [`Note`] for further manipulation.
-  pub fn pitch_mut(&mut self) -> &mut Pitch { &mut self.pitch }
+  pub const fn pitch_mut(&mut self) -> &mut Pitch { &mut self.pitch }
+}
+
+impl TryFrom<&str> for Note {
+  type Error = PitchError;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    Pitch::try_from(value).map(Self::new_from_pitch)
+  }
+}
+
+impl Transpose for Note {
+  fn transposed(&self, transposition: Transposition) -> Self {
+    Self::new_from_pitch(self.pitch.transposed(transposition))
+  }
 }
@@ -15,23 +15,25 @@
 // Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
 // SPDX-License-Identifier: GPL-3.0-only
 
-use std::collections::HashMap;
+use crate::{
+  accidental::Accidental::{Natural, Sharp},
+  letter::Letter::{A, B, C, D, E, F, G},
+  pitch_class::PitchClass,
+};
 
-use once_cell::sync::Lazy;
-
-pub const NOTES: [&str; 12] = [
-  "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+/// The twelve chromatic [`PitchClass`]es, starting from C and spelled with
+/// sharps.
+pub const NOTES: [PitchClass; 12] = [
+  PitchClass::new(C, Natural),
+  PitchClass::new(C, Sharp),
+  PitchClass::new(D, Natural),
+  PitchClass::new(D, Sharp),
+  PitchClass::new(E, Natural),
+  PitchClass::new(F, Natural),
+  PitchClass::new(F, Sharp),
+  PitchClass::new(G, Natural),
+  PitchClass::new(G, Sharp),
+  PitchClass::new(A, Natural),
+  PitchClass::new(A, Sharp),
+  PitchClass::new(B, Natural),
 ];
-
-pub static NOTES_OFFSET: Lazy<HashMap<String, i8>> = Lazy::new(|| {
-  let mut notes = HashMap::new();
-  let mut i = -9;
-
-  for note in NOTES {
-    notes.insert(note.to_string(), i);
-
-    i += 1;
-  }
-
-  notes
-});
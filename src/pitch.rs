@@ -15,32 +15,83 @@
 // Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
 // SPDX-License-Identifier: GPL-3.0-only
 
+use std::{fmt, str::FromStr};
+
 use crate::{
+  accidental::Accidental,
   convert,
   convert::{frequency_to_pitch, semitones_to_frequency},
+  error::PitchError,
+  letter::Letter,
+  pitch_class::PitchClass,
+  reference::Reference,
+  temperament::{EqualTemperament, Temperament},
   unit::{Cent, Frequency, MidiNote, Octave, Semitone},
-  utility::capitalize_first_letter,
 };
 
 /// A structure which represents the [`Pitch`] of a [`Note`].
 ///
+/// A [`Pitch`] can also be parsed from scientific pitch notation ("C#4",
+/// "Bb-1", "A♯4") or Helmholtz pitch notation ("c'", "C,"), and is displayed
+/// in scientific pitch notation.
+///
+/// A [`Pitch`] which lies between two equal-tempered notes is spelled as the
+/// nearest one, and carries its signed deviation from it in [`Cent`]s, which
+/// is displayed after the note when it is at least a tenth of a [`Cent`].
+///
+/// A [`Pitch`] is tuned in twelve-tone equal temperament relative to
+/// [`Reference::A440`], unless it is given another [`Temperament`] with
+/// [`Pitch::with_temperament`] or another [`Reference`] with
+/// [`Pitch::set_reference`].
+///
 /// # Examples
 ///
 /// ```rust
-/// let _ = guitar::Pitch::new("C", 4); 
+/// let _ = guitar::Pitch::new("C", 4);
+///
+/// let pitch = "E♭3".parse::<guitar::Pitch>().unwrap();
+///
+/// assert_eq!(pitch.to_string(), "Eb3");
+/// assert_eq!(format!("{pitch:#}"), "E♭3");
+///
+/// let sharp = guitar::Pitch::new_from_frequency(445.);
+///
+/// assert_eq!(sharp.to_string(), "A4 +19.6¢");
+/// assert_eq!(
+///   "A4 +19.6¢".parse::<guitar::Pitch>().unwrap().cent_offset(),
+///   19.6
+/// );
 /// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
-pub struct Pitch {
-  pitch: String,
+pub struct Pitch<T = EqualTemperament> {
+  class: PitchClass,
   frequency: Frequency,
-  base_frequency: Frequency,
-  base_midi_note: MidiNote,
+  reference: Reference,
   octave: Octave,
+  cent_offset: Cent,
+  temperament: T,
 }
 
 impl Pitch {
+  /// The highest [`Octave`] accepted by the fallible constructors. It holds
+  /// the highest MIDI note, G9, though G#9 through B9 are accepted too and lie
+  /// above the MIDI range.
+  pub const MAX_OCTAVE: Octave = 9;
+  /// The lowest [`Octave`] accepted by the fallible constructors; C-1 is the
+  /// lowest MIDI note.
+  pub const MIN_OCTAVE: Octave = -1;
+
   /// Creates a new [`Pitch`] from the name of a [`Pitch`].
   ///
+  /// The spelling of the name is kept as-is, and the [`Octave`] follows the
+  /// letter of the name, so "Cb" in octave 4 sounds as B3.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `pitch` isn't a valid [`PitchClass`] name; see
+  /// [`Pitch::try_new`] for a fallible alternative which also checks the
+  /// [`Octave`].
+  ///
   /// # Examples
   ///
   /// ```rust
@@ -48,69 +99,124 @@ impl Pitch {
   /// ```
   #[must_use]
   pub fn new(pitch: &str, octave: Octave) -> Self {
-    let mut pitch = capitalize_first_letter(pitch);
+    let class = pitch
+      .parse::<PitchClass>()
+      .unwrap_or_else(|error| panic!("{error}"));
 
-    if pitch.ends_with('b') {
-      if let Some(enharmonic) = convert::enharmonic(&pitch) {
-        pitch = enharmonic;
-      }
-    }
+    Self::new_from_pitch_class(class, octave)
+  }
+
+  /// Creates a new [`Pitch`] from the name of a [`Pitch`], reporting an
+  /// invalid name or out of range [`Octave`] as a [`PitchError`].
+  ///
+  /// # Errors
+  ///
+  /// Returns a [`PitchError`] if `pitch` isn't a valid [`PitchClass`] name or
+  /// `octave` is outside of [`Pitch::MIN_OCTAVE`] and [`Pitch::MAX_OCTAVE`].
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{Pitch, PitchError};
+  ///
+  /// assert!(Pitch::try_new("Eb", 4).is_ok());
+  /// assert_eq!(Pitch::try_new("H", 4), Err(PitchError::UnknownLetter('H')));
+  /// assert_eq!(
+  ///   Pitch::try_new("C", 12),
+  ///   Err(PitchError::OctaveOutOfRange(12))
+  /// );
+  /// ```
+  pub fn try_new(pitch: &str, octave: Octave) -> Result<Self, PitchError> {
+    let class = pitch.parse::<PitchClass>()?;
+
+    Ok(Self::new_from_pitch_class(
+      class,
+      Self::check_octave(octave)?,
+    ))
+  }
 
+  /// Creates a new [`Pitch`] from a [`PitchClass`] and an [`Octave`].
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{Letter, Pitch, PitchClass};
+  ///
+  /// let _ = Pitch::new_from_pitch_class(PitchClass::natural(Letter::C), 4);
+  /// ```
+  #[must_use]
+  pub fn new_from_pitch_class(class: PitchClass, octave: Octave) -> Self {
     Self {
-      pitch: pitch.to_string(),
+      class,
       frequency: convert::pitch_and_octave_to_frequency(
-        &pitch, octave, 440., 69,
+        class, octave, 440., 69,
       ),
-      base_frequency: 440.,
-      base_midi_note: 69,
+      reference: Reference::A440,
       octave,
+      cent_offset: 0.,
+      temperament: EqualTemperament::default(),
     }
   }
 
   /// Creates a new [`Pitch`] given all parameters.
   ///
-  /// Be careful when using this function, as it doesn't check if the given
-  /// [`Pitch`] is valid.
+  /// The [`Cent`] offset is the deviation of `frequency` from the
+  /// equal-tempered [`Frequency`] of `class` in `octave`. Be careful when
+  /// using this function, as it doesn't check if the given [`Pitch`] is
+  /// valid.
   #[must_use]
   pub fn new_from_complete(
-    pitch: &str,
+    class: PitchClass,
     frequency: Frequency,
     base_frequency: Frequency,
     base_midi_note: MidiNote,
     octave: Octave,
   ) -> Self {
     Self {
-      pitch: pitch.to_string(),
+      class,
       frequency,
-      base_frequency,
-      base_midi_note,
+      reference: Reference::new_from_midi_note(base_midi_note, base_frequency),
       octave,
+      cent_offset: convert::cents_between_frequencies(
+        frequency,
+        convert::pitch_and_octave_to_frequency(
+          class,
+          octave,
+          base_frequency,
+          base_midi_note,
+        ),
+      ),
+      temperament: EqualTemperament::default(),
     }
   }
 
   /// Creates a new [`Pitch`] with
   #[must_use]
   pub fn new_from_builder(
-    pitch: Option<&str>,
+    class: Option<PitchClass>,
     frequency: Option<Frequency>,
     base_frequency: Frequency,
     base_midi_note: MidiNote,
     octave: Option<Octave>,
   ) -> Self {
-    if let (Some(pitch), Some(_), Some(octave)) = (pitch, frequency, octave) {
-      Self::new(pitch, octave)
-    } else if let (Some(pitch), Some(octave)) = (pitch, octave) {
+    if let (Some(class), Some(_), Some(octave)) = (class, frequency, octave) {
+      Self::new_from_pitch_class(class, octave)
+    } else if let (Some(class), Some(octave)) = (class, octave) {
       Self {
-        pitch: pitch.to_string(),
+        class,
         frequency: convert::pitch_and_octave_to_frequency(
-          pitch,
+          class,
           octave,
           base_frequency,
           base_midi_note,
         ),
-        base_frequency,
-        base_midi_note,
+        reference: Reference::new_from_midi_note(
+          base_midi_note,
+          base_frequency,
+        ),
         octave,
+        cent_offset: 0.,
+        temperament: EqualTemperament::default(),
       }
     } else if let Some(frequency) = frequency {
       frequency_to_pitch(frequency, base_frequency, base_midi_note)
@@ -131,6 +237,43 @@ impl Pitch {
     frequency_to_pitch(frequency, 440., 69)
   }
 
+  /// Creates a new [`Pitch`] from a [`Frequency`], reporting a frequency
+  /// which can't belong to a [`Pitch`] as a [`PitchError`].
+  ///
+  /// # Errors
+  ///
+  /// Returns a [`PitchError`] if `frequency` isn't finite and positive, or if
+  /// the nearest [`Pitch`] is out of range.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{Pitch, PitchError};
+  ///
+  /// assert!(Pitch::try_new_from_frequency(493.88).is_ok());
+  /// assert!(matches!(
+  ///   Pitch::try_new_from_frequency(f64::NAN),
+  ///   Err(PitchError::NonFiniteFrequency(_))
+  /// ));
+  /// ```
+  pub fn try_new_from_frequency(
+    frequency: Frequency,
+  ) -> Result<Self, PitchError> {
+    if !frequency.is_finite() {
+      return Err(PitchError::NonFiniteFrequency(frequency));
+    }
+
+    if frequency <= 0. {
+      return Err(PitchError::NonPositiveFrequency(frequency));
+    }
+
+    let pitch = Self::new_from_frequency(frequency);
+
+    Self::check_octave(pitch.octave)?;
+
+    Ok(pitch)
+  }
+
   /// Creates a new [`Pitch`] from the number of [`Semitone`]s above the
   /// default base frequency of 440 Hz.
   ///
@@ -146,61 +289,156 @@ impl Pitch {
     frequency_to_pitch(frequency, 440., 69)
   }
 
-  /// Returns the pitch of the [`Pitch`].
+  const fn check_octave(octave: Octave) -> Result<Octave, PitchError> {
+    if octave < Self::MIN_OCTAVE || octave > Self::MAX_OCTAVE {
+      Err(PitchError::OctaveOutOfRange(octave))
+    } else {
+      Ok(octave)
+    }
+  }
+}
+
+impl<T: Temperament> Pitch<T> {
+  /// Returns the [`Pitch`] tuned in another [`Temperament`], keeping its
+  /// spelling, [`Octave`], and [`Cent`] offset.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{temperament::Meantone, Pitch};
+  ///
+  /// let c = Pitch::new("C", 5).with_temperament(Meantone::QUARTER_COMMA);
+  /// let e = Pitch::new("E", 5).with_temperament(Meantone::QUARTER_COMMA);
+  ///
+  /// // Quarter-comma meantone tunes major thirds pure.
+  /// assert!((e.frequency() / c.frequency() - 1.25).abs() < 1e-12);
+  /// assert_eq!(e.to_string(), "E5");
+  /// ```
   #[must_use]
-  pub fn pitch(&self) -> &str { &self.pitch }
-
-  /// Sets the name of the [`Pitch`].
-  pub fn set_pitch(&mut self, pitch: &str) {
-    self.pitch = capitalize_first_letter(pitch);
-    self.frequency = convert::pitch_and_octave_to_frequency(
-      &self.pitch,
-      self.octave,
-      self.base_frequency,
-      self.base_midi_note,
-    );
+  pub fn with_temperament<U: Temperament>(self, temperament: U) -> Pitch<U> {
+    let mut pitch = Pitch {
+      class: self.class,
+      frequency: self.frequency,
+      reference: self.reference,
+      octave: self.octave,
+      cent_offset: self.cent_offset,
+      temperament,
+    };
+
+    pitch.retune();
+
+    pitch
+  }
+
+  /// Returns the [`Temperament`] of the [`Pitch`].
+  #[must_use]
+  pub const fn temperament(&self) -> &T { &self.temperament }
+
+  /// Returns the [`PitchClass`] of the [`Pitch`].
+  #[must_use]
+  pub const fn pitch_class(&self) -> PitchClass { self.class }
+
+  /// Returns the [`Letter`] of the [`Pitch`].
+  #[must_use]
+  pub const fn letter(&self) -> Letter { self.class.letter() }
+
+  /// Returns the [`Accidental`] of the [`Pitch`].
+  #[must_use]
+  pub const fn accidental(&self) -> Accidental { self.class.accidental() }
+
+  /// Sets the [`PitchClass`] of the [`Pitch`], keeping its [`Octave`] and
+  /// [`Cent`] offset.
+  pub fn set_pitch_class(&mut self, class: PitchClass) {
+    self.class = class;
+    self.retune();
   }
 
   /// Returns the [`Frequency`] of the [`Note`].
   #[must_use]
   pub const fn frequency(&self) -> Frequency { self.frequency }
 
-  /// Sets the [`Note`]'s [`Frequency`].
+  /// Sets the [`Note`]'s [`Frequency`], respelling it as the nearest
+  /// equal-tempered note and keeping its distance from that note in the
+  /// [`Pitch`]'s [`Temperament`] as its [`Cent`] offset.
   pub fn set_frequency(&mut self, frequency: Frequency) {
+    let pitch = frequency_to_pitch(
+      frequency,
+      self.reference.frequency(),
+      self.reference.midi_note(),
+    );
+
     self.frequency = frequency;
-    self.octave = convert::frequency_to_octave(frequency, self.base_frequency);
-    self.pitch =
-      frequency_to_pitch(frequency, self.base_frequency, self.base_midi_note)
-        .pitch()
-        .to_string();
+    self.octave = pitch.octave;
+    self.class = pitch.class;
+    self.cent_offset = self.offset_of(frequency);
+  }
+
+  /// Returns the signed number of [`Cent`]s that the [`Pitch`] deviates from
+  /// the note it is spelled as in its [`Temperament`].
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// let pitch = guitar::Pitch::new_from_frequency(435.);
+  ///
+  /// assert_eq!(pitch.to_string(), "A4 -19.8¢");
+  /// assert!((pitch.cent_offset() + 19.79).abs() < 0.01);
+  /// ```
+  #[must_use]
+  pub const fn cent_offset(&self) -> Cent { self.cent_offset }
+
+  /// Sets the signed number of [`Cent`]s that the [`Pitch`] deviates from the
+  /// note it is spelled as in its [`Temperament`], keeping its spelling.
+  pub fn set_cent_offset(&mut self, cents: Cent) {
+    self.cent_offset = cents;
+    self.retune();
   }
 
-  /// Returns the [`Note`]'s base [`Frequency`].
+  /// Returns the [`Reference`] which the [`Pitch`] is tuned relative to.
   #[must_use]
-  pub const fn base_frequency(&self) -> Frequency { self.base_frequency }
+  pub const fn reference(&self) -> Reference { self.reference }
 
-  /// Sets the [`Note`]'s base [`Frequency`].
+  /// Sets the [`Reference`] which the [`Pitch`] is tuned relative to, keeping
+  /// its spelling, [`Octave`], and [`Cent`] offset.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{Pitch, Reference};
+  ///
+  /// let mut e = Pitch::new("E", 4);
+  ///
+  /// e.set_reference(Reference::A415);
+  ///
+  /// assert_eq!(e.to_string(), "E4");
+  /// assert!((e.frequency() - 310.90).abs() < 0.01);
+  /// ```
+  pub fn set_reference(&mut self, reference: Reference) {
+    self.reference = reference;
+    self.retune();
+  }
+
+  /// Returns the [`Frequency`] of the [`Note`]'s [`Reference`].
+  #[must_use]
+  pub const fn base_frequency(&self) -> Frequency { self.reference.frequency() }
+
+  /// Sets the [`Frequency`] of the [`Note`]'s [`Reference`], keeping its
+  /// anchor note.
   pub fn set_base_frequency(&mut self, frequency: Frequency) {
-    self.base_frequency = frequency;
-    self.base_midi_note =
-      convert::frequency_to_midi_note(frequency, self.base_frequency);
-    self.frequency = semitones_to_frequency(self.semitones(), frequency);
+    self.set_reference(self.reference.with_frequency(frequency));
   }
 
-  /// Returns the [`Note`]'s base MIDI note.
+  /// Returns the anchor note of the [`Note`]'s [`Reference`] as a MIDI note.
   #[must_use]
-  pub const fn base_midi_note(&self) -> MidiNote { self.base_midi_note }
+  pub const fn base_midi_note(&self) -> MidiNote { self.reference.midi_note() }
 
-  /// Sets the [`Note`]'s base MIDI note.
+  /// Moves the anchor note of the [`Note`]'s [`Reference`] to a MIDI note,
+  /// keeping its [`Frequency`].
   pub fn set_base_midi_note(&mut self, midi_note: MidiNote) {
-    self.base_midi_note = midi_note;
-    self.base_frequency = convert::midi_note_to_frequency(
+    self.set_reference(Reference::new_from_midi_note(
       midi_note,
-      self.base_frequency,
-      self.base_midi_note,
-    );
-    self.frequency =
-      semitones_to_frequency(self.semitones(), self.base_frequency);
+      self.reference.frequency(),
+    ));
   }
 
   /// Returns the [`Octave`] of the [`Note`].
@@ -210,61 +448,226 @@ impl Pitch {
   /// Sets the [`Note`]'s [`Octave`].
   pub fn set_octave(&mut self, octave: Octave) {
     self.octave = octave;
-    self.frequency = convert::pitch_and_octave_to_frequency(
-      &self.pitch,
-      self.octave,
-      self.base_frequency,
-      self.base_midi_note,
-    );
+    self.retune();
   }
 
   /// Returns the [`Note`]'s enharmonic pair, if it exists.
+  ///
+  /// The [`Octave`] is adjusted when the respelling crosses C, so the
+  /// enharmonic of Cb4 is B3.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// let enharmonic = guitar::Pitch::new("Cb", 4).enharmonic().unwrap();
+  ///
+  /// assert_eq!(enharmonic.pitch_class().to_string(), "B");
+  /// assert_eq!(enharmonic.octave(), 3);
+  /// ```
   #[must_use]
   pub fn enharmonic(&self) -> Option<Self> {
-    let enharmonic = convert::enharmonic(&self.pitch);
-
-    enharmonic.map(|enharmonic| {
-      Self::new_from_complete(
-        &enharmonic,
-        self.frequency,
-        self.base_frequency,
-        self.base_midi_note,
-        self.octave,
-      )
+    self
+      .class
+      .enharmonic()
+      .and_then(|class| self.respell(class))
+  }
+
+  /// Returns the [`Pitch`] spelled with another [`PitchClass`] which sounds
+  /// the same, adjusting the [`Octave`] when the respelling crosses C.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{Pitch, PitchClass};
+  ///
+  /// let c = Pitch::new("C", 4);
+  ///
+  /// assert_eq!(
+  ///   c.respell(PitchClass::from_name("B#").unwrap())
+  ///     .unwrap()
+  ///     .to_string(),
+  ///   "B#3",
+  /// );
+  /// assert!(c.respell(PitchClass::from_name("D").unwrap()).is_none());
+  /// ```
+  #[must_use]
+  pub fn respell(&self, class: PitchClass) -> Option<Self> {
+    self.class.is_enharmonic(class).then(|| {
+      let mut pitch = self.clone();
+
+      pitch.class = class;
+      pitch.octave = (self.octave * 12 + self.class.offset() - class.offset())
+        .div_euclid(12);
+      pitch.cent_offset = pitch.offset_of(self.frequency);
+
+      pitch
     })
   }
 
   /// Returns the number of [`Semitone`]s that the [`Note`] is away from the
-  /// base frequency.
+  /// anchor note of its [`Reference`], ignoring its [`Cent`] offset.
   #[must_use]
-  pub fn semitones(&self) -> Semitone {
-    convert::frequency_to_semitones(self.frequency, self.base_frequency)
+  pub const fn semitones(&self) -> Semitone {
+    self.midi_note() - self.reference.midi_note()
   }
 
-  /// Sets the number of [`Semitone`]s that the [`Note`]'s is away from the base
-  /// frequency.
+  /// Sets the number of [`Semitone`]s that the [`Note`]'s is away from the
+  /// anchor note of its [`Reference`], spelling it with sharps and keeping its
+  /// [`Cent`] offset.
   pub fn set_semitones(&mut self, semitones: Semitone) {
-    self.frequency = semitones_to_frequency(semitones, self.base_frequency);
-    self.octave =
-      convert::frequency_to_octave(self.frequency, self.base_frequency);
-    self.pitch = frequency_to_pitch(
-      self.frequency,
-      self.base_frequency,
-      self.base_midi_note,
-    )
-    .pitch()
-    .to_string();
+    let midi_note = self.reference.midi_note() + semitones;
+
+    self.class = PitchClass::from_semitones(midi_note);
+    self.octave = midi_note.div_euclid(12) - 1;
+    self.retune();
   }
 
-  /// Returns the [`Note`]'s representation as a MIDI note.
+  /// Returns the [`Note`]'s representation as a MIDI note, ignoring its
+  /// [`Cent`] offset.
   #[must_use]
-  pub fn midi_note(&self) -> MidiNote {
-    convert::frequency_to_midi_note(self.frequency, self.base_frequency)
+  pub const fn midi_note(&self) -> MidiNote {
+    convert::pitch_and_octave_to_semitones(self.class, self.octave) + 69
   }
 
-  /// Returns the [`Note`]'s representation in [`Cent`]s.
+  /// Returns the number of [`Cent`]s that the [`Note`] is away from the
+  /// anchor note of its [`Reference`], including its [`Temperament`] and
+  /// [`Cent`] offset.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// let pitch = guitar::Pitch::new_from_frequency(445.);
+  ///
+  /// assert!((pitch.cents() - 19.56).abs() < 0.01);
+  /// assert_eq!(guitar::Pitch::new("C", 5).cents(), 300.);
+  /// ```
   #[must_use]
+  #[allow(clippy::cast_precision_loss)]
   pub fn cents(&self) -> Cent {
-    convert::semitones_to_cents(self.semitones()) as Cent
+    ((self.octave - self.reference.octave()) as Cent).mul_add(
+      1200.,
+      self.temperament.cents(self.class)
+        - self.temperament.cents(self.reference.pitch_class()),
+    ) + self.cent_offset
+  }
+
+  /// Returns the [`Frequency`] of the note which the [`Pitch`] is spelled as
+  /// in its [`Temperament`], ignoring its [`Cent`] offset.
+  fn tempered_frequency(&self) -> Frequency {
+    self
+      .temperament
+      .frequency(self.class, self.octave, self.reference)
+  }
+
+  /// Returns the number of [`Cent`]s that a [`Frequency`] deviates from the
+  /// note which the [`Pitch`] is spelled as.
+  fn offset_of(&self, frequency: Frequency) -> Cent {
+    convert::cents_between_frequencies(frequency, self.tempered_frequency())
+  }
+
+  /// Recomputes the [`Frequency`] from the spelling, [`Octave`], [`Cent`]
+  /// offset, and [`Temperament`].
+  fn retune(&mut self) {
+    self.frequency =
+      convert::cents_to_frequency(self.cent_offset, self.tempered_frequency());
+  }
+
+  /// Returns the [`Pitch`] in Helmholtz pitch notation.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// assert_eq!(guitar::Pitch::new("C", 4).helmholtz(), "c'");
+  /// assert_eq!(guitar::Pitch::new("Bb", 1).helmholtz(), "Bb,");
+  /// ```
+  #[must_use]
+  pub fn helmholtz(&self) -> String {
+    let letter = if self.octave >= 3 {
+      self.letter().to_char().to_ascii_lowercase()
+    } else {
+      self.letter().to_char()
+    };
+
+    format!(
+      "{letter}{}{}",
+      self.accidental(),
+      convert::octave_to_helmholtz_marks(self.octave)
+    )
+  }
+}
+
+impl<T> fmt::Display for Pitch<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if f.alternate() {
+      write!(f, "{:#}{}", self.class, self.octave)?;
+    } else {
+      write!(f, "{}{}", self.class, self.octave)?;
+    }
+
+    if (self.cent_offset * 10.).round() == 0. {
+      Ok(())
+    } else {
+      write!(f, " {:+.1}¢", self.cent_offset)
+    }
+  }
+}
+
+impl FromStr for Pitch {
+  type Err = PitchError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (s, cents) = s.split_once(char::is_whitespace).unwrap_or((s, ""));
+    let first = s.chars().next().ok_or(PitchError::Empty)?;
+    let name_length = first.len_utf8()
+      + s[first.len_utf8()..]
+        .chars()
+        .take_while(|&character| Accidental::is_symbol(character))
+        .map(char::len_utf8)
+        .sum::<usize>();
+    let (name, octave) = s.split_at(name_length);
+    let class = name.parse::<PitchClass>()?;
+    let octave = octave
+      .parse::<Octave>()
+      .ok()
+      .or_else(|| {
+        convert::helmholtz_marks_to_octave(first.is_uppercase(), octave)
+      })
+      .ok_or_else(|| PitchError::InvalidOctave(octave.to_string()))?;
+
+    let mut pitch = Self::new_from_pitch_class(class, octave);
+
+    if !cents.trim().is_empty() {
+      pitch.set_cent_offset(
+        cents
+          .trim()
+          .trim_end_matches(['¢', 'c'])
+          .parse()
+          .map_err(|_| PitchError::InvalidCentOffset(cents.to_string()))?,
+      );
+    }
+
+    Ok(pitch)
+  }
+}
+
+impl TryFrom<&str> for Pitch {
+  type Error = PitchError;
+
+  /// Parses a [`Pitch`] like [`str::parse`], but also reports an [`Octave`]
+  /// outside of [`Pitch::MIN_OCTAVE`] and [`Pitch::MAX_OCTAVE`].
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    let pitch = value.parse::<Self>()?;
+
+    Self::check_octave(pitch.octave)?;
+
+    Ok(pitch)
+  }
+}
+
+impl TryFrom<Frequency> for Pitch {
+  type Error = PitchError;
+
+  fn try_from(value: Frequency) -> Result<Self, Self::Error> {
+    Self::try_new_from_frequency(value)
   }
 }
@@ -0,0 +1,280 @@
+// This file is part of Guitar <https://github.com/Fuwn/guitar>.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+//
+// Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::{fmt, str::FromStr};
+
+use crate::{
+  accidental::Accidental, error::PitchError, letter::Letter, notes::NOTES,
+  unit::Semitone,
+};
+
+/// A spelled pitch class: a [`Letter`] and an [`Accidental`], without an
+/// octave.
+///
+/// Two [`PitchClass`]es may sound the same while being spelled differently
+/// (e.g., C# and Db); equality compares spelling, while
+/// [`PitchClass::is_enharmonic`] compares sound.
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::PitchClass;
+///
+/// let b_sharp = PitchClass::from_name("B#").unwrap();
+///
+/// assert_eq!(b_sharp.semitones(), 0);
+/// assert_eq!(b_sharp.to_string(), "B#");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PitchClass {
+  letter: Letter,
+  accidental: Accidental,
+}
+
+impl PitchClass {
+  /// Creates a new [`PitchClass`] from a [`Letter`] and an [`Accidental`].
+  #[must_use]
+  pub const fn new(letter: Letter, accidental: Accidental) -> Self {
+    Self { letter, accidental }
+  }
+
+  /// Creates a new, natural [`PitchClass`] from a [`Letter`].
+  #[must_use]
+  pub const fn natural(letter: Letter) -> Self {
+    Self::new(letter, Accidental::Natural)
+  }
+
+  /// Creates a new [`PitchClass`] from a name such as "C", "f#", "Bb", "Ebb",
+  /// "Gx", or "A♯".
+  ///
+  /// This is a shorthand for parsing the name with [`str::parse`] and
+  /// discarding the [`PitchError`].
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{Accidental, Letter, PitchClass};
+  ///
+  /// assert_eq!(
+  ///   PitchClass::from_name("Fbb"),
+  ///   Some(PitchClass::new(Letter::F, Accidental::DoubleFlat)),
+  /// );
+  /// assert_eq!(PitchClass::from_name("H"), None);
+  /// ```
+  #[must_use]
+  pub fn from_name(name: &str) -> Option<Self> { name.parse().ok() }
+
+  /// Creates a new [`PitchClass`] from a number of [`Semitone`]s above C,
+  /// spelled with sharps.
+  #[must_use]
+  #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+  pub const fn from_semitones(semitones: Semitone) -> Self {
+    NOTES[semitones.rem_euclid(12) as usize]
+  }
+
+  /// Spells a number of [`Semitone`]s above C using a specific [`Letter`], if
+  /// it is reachable with at most a double accidental.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{Letter, PitchClass};
+  ///
+  /// assert_eq!(
+  ///   PitchClass::spell(Letter::E, 5).map(|c| c.to_string()),
+  ///   Some("E#".to_string()),
+  /// );
+  /// assert_eq!(PitchClass::spell(Letter::C, 6), None);
+  /// ```
+  #[must_use]
+  pub const fn spell(letter: Letter, semitones: Semitone) -> Option<Self> {
+    let alteration = (semitones - letter.semitones() + 6).rem_euclid(12) - 6;
+
+    match Accidental::from_semitones(alteration) {
+      Some(accidental) => Some(Self::new(letter, accidental)),
+      None => None,
+    }
+  }
+
+  /// Creates a new [`PitchClass`] from its position on the line of fifths,
+  /// where C is 0, G is 1, and F is -1, if it needs at most a double
+  /// accidental.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::PitchClass;
+  ///
+  /// assert_eq!(
+  ///   PitchClass::from_fifths(-2)
+  ///     .map(|c| c.to_string())
+  ///     .as_deref(),
+  ///   Some("Bb")
+  /// );
+  /// assert_eq!(
+  ///   PitchClass::from_fifths(7).map(|c| c.to_string()).as_deref(),
+  ///   Some("C#")
+  /// );
+  /// ```
+  #[must_use]
+  #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+  pub const fn from_fifths(fifths: i64) -> Option<Self> {
+    const LETTERS: [Letter; 7] = [
+      Letter::F,
+      Letter::C,
+      Letter::G,
+      Letter::D,
+      Letter::A,
+      Letter::E,
+      Letter::B,
+    ];
+
+    match Accidental::from_semitones((fifths + 1).div_euclid(7)) {
+      Some(accidental) => Some(Self::new(
+        LETTERS[(fifths + 1).rem_euclid(7) as usize],
+        accidental,
+      )),
+      None => None,
+    }
+  }
+
+  /// Returns the [`Letter`] of the [`PitchClass`].
+  #[must_use]
+  pub const fn letter(self) -> Letter { self.letter }
+
+  /// Returns the [`Accidental`] of the [`PitchClass`].
+  #[must_use]
+  pub const fn accidental(self) -> Accidental { self.accidental }
+
+  /// Returns the number of [`Semitone`]s that the [`PitchClass`] is above C,
+  /// in the range `0..12`.
+  #[must_use]
+  pub const fn semitones(self) -> Semitone { self.offset().rem_euclid(12) }
+
+  /// Returns the number of [`Semitone`]s that the [`PitchClass`] is above the
+  /// natural C of its own octave.
+  ///
+  /// Unlike [`PitchClass::semitones`], this isn't wrapped, so Cb is `-1` and
+  /// B# is `12`.
+  #[must_use]
+  pub const fn offset(self) -> Semitone {
+    self.letter.semitones() + self.accidental.semitones()
+  }
+
+  /// Returns the position of the [`PitchClass`] on the line of fifths, where
+  /// C is 0, sharps are positive, and flats are negative; the inverse of
+  /// [`PitchClass::from_fifths`].
+  #[must_use]
+  pub const fn fifths(self) -> i64 {
+    let natural = match self.letter {
+      Letter::F => -1,
+      Letter::C => 0,
+      Letter::G => 1,
+      Letter::D => 2,
+      Letter::A => 3,
+      Letter::E => 4,
+      Letter::B => 5,
+    };
+
+    natural + self.accidental.semitones() * 7
+  }
+
+  /// Returns whether two [`PitchClass`]es sound the same, regardless of
+  /// spelling.
+  #[must_use]
+  pub const fn is_enharmonic(self, other: Self) -> bool {
+    self.semitones() == other.semitones()
+  }
+
+  /// Returns every spelling of the [`PitchClass`], including itself, in
+  /// [`Letter`] order.
+  #[must_use]
+  pub fn enharmonics(self) -> Vec<Self> {
+    Letter::ALL
+      .iter()
+      .filter_map(|&letter| Self::spell(letter, self.semitones()))
+      .collect()
+  }
+
+  /// Returns the conventional respelling of the [`PitchClass`], if it has an
+  /// accidental.
+  ///
+  /// A natural spelling is preferred, followed by a single accidental in the
+  /// same direction, followed by a single accidental in the other direction.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::PitchClass;
+  ///
+  /// let enharmonic = |name| {
+  ///   PitchClass::from_name(name)
+  ///     .and_then(PitchClass::enharmonic)
+  ///     .map(|c| c.to_string())
+  /// };
+  ///
+  /// assert_eq!(enharmonic("Cb").as_deref(), Some("B"));
+  /// assert_eq!(enharmonic("C#").as_deref(), Some("Db"));
+  /// assert_eq!(enharmonic("Fbb").as_deref(), Some("Eb"));
+  /// assert_eq!(enharmonic("E"), None);
+  /// ```
+  #[must_use]
+  pub fn enharmonic(self) -> Option<Self> {
+    if self.accidental == Accidental::Natural {
+      return None;
+    }
+
+    let direction = self.accidental.semitones().signum();
+
+    self
+      .enharmonics()
+      .into_iter()
+      .filter(|class| *class != self && class.accidental.semitones().abs() <= 1)
+      .min_by_key(|class| {
+        let alteration = class.accidental.semitones();
+
+        (alteration != 0, alteration != direction)
+      })
+  }
+}
+
+impl fmt::Display for PitchClass {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if f.alternate() {
+      write!(f, "{}{:#}", self.letter, self.accidental)
+    } else {
+      write!(f, "{}{}", self.letter, self.accidental)
+    }
+  }
+}
+
+impl FromStr for PitchClass {
+  type Err = PitchError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let mut characters = s.chars();
+    let first = characters.next().ok_or(PitchError::Empty)?;
+    let letter =
+      Letter::from_char(first).ok_or(PitchError::UnknownLetter(first))?;
+    let accidental =
+      Accidental::from_symbol(characters.as_str()).ok_or_else(|| {
+        PitchError::InvalidAccidental(characters.as_str().to_string())
+      })?;
+
+    Ok(Self::new(letter, accidental))
+  }
+}
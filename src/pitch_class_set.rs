@@ -0,0 +1,408 @@
+// This file is part of Guitar <https://github.com/Fuwn/guitar>.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+//
+// Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
+// SPDX-License-Identifier: GPL-3.0-only
+
+#![allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+
+use std::fmt;
+
+use crate::{
+  notes::NOTES, pitch::Pitch, pitch_class::PitchClass, unit::Semitone,
+};
+
+/// Representatives of Forte's trichords, tetrachords, pentachords, and
+/// hexachords, in catalogue order. Pitch classes ten and eleven are written as
+/// "A" and "B".
+const CATALOGUE: [&[&str]; 4] = [
+  &[
+    "012", "013", "014", "015", "016", "024", "025", "026", "027", "036",
+    "037", "048",
+  ],
+  &[
+    "0123", "0124", "0134", "0125", "0126", "0127", "0145", "0156", "0167",
+    "0235", "0135", "0236", "0136", "0237", "0146", "0157", "0347", "0147",
+    "0148", "0158", "0246", "0247", "0257", "0248", "0268", "0358", "0258",
+    "0369", "0137",
+  ],
+  &[
+    "01234", "01235", "01245", "01236", "01237", "01256", "01267", "02346",
+    "01246", "01346", "02347", "01356", "01248", "01257", "01268", "01347",
+    "01348", "01457", "01367", "01568", "01458", "01478", "02357", "01357",
+    "02358", "02458", "01358", "02368", "01368", "01468", "01369", "01469",
+    "02468", "02469", "02479", "01247", "03458", "01258",
+  ],
+  &[
+    "012345", "012346", "012356", "012456", "012367", "012567", "012678",
+    "023457", "012357", "013457", "012457", "012467", "013467", "013458",
+    "012458", "014568", "012478", "012578", "013478", "014589", "023468",
+    "012468", "023568", "013468", "013568", "013578", "013469", "013569",
+    "013689", "013679", "014579", "024579", "023579", "013579", "02468A",
+    "012347", "012348", "012378", "023458", "012358", "012368", "012369",
+    "012568", "012569", "023469", "012469", "012479", "012579", "013479",
+    "014679",
+  ],
+];
+
+/// The name of a set class in Forte's catalogue, e.g., "4-Z15".
+///
+/// Sets of seven to ten pitch classes share the ordinal of their complement,
+/// dyads are numbered by their interval class, and the empty set, single pitch
+/// classes, and their complements are numbered one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ForteNumber {
+  cardinality: usize,
+  ordinal: usize,
+  z: bool,
+}
+
+impl ForteNumber {
+  /// Returns the number of pitch classes in the set class.
+  #[must_use]
+  pub const fn cardinality(self) -> usize { self.cardinality }
+
+  /// Returns the position of the set class within its cardinality.
+  #[must_use]
+  pub const fn ordinal(self) -> usize { self.ordinal }
+
+  /// Returns whether the set class shares its interval-class vector with
+  /// another set class.
+  #[must_use]
+  pub const fn is_z(self) -> bool { self.z }
+}
+
+impl fmt::Display for ForteNumber {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{}-{}{}",
+      self.cardinality,
+      if self.z { "Z" } else { "" },
+      self.ordinal
+    )
+  }
+}
+
+/// An unordered set of pitch classes, represented as integers from zero (C) to
+/// eleven (B).
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::PitchClassSet;
+///
+/// let set = PitchClassSet::new(&[11, 0, 4]);
+///
+/// assert_eq!(set.normal_order(), [11, 0, 4]);
+/// assert_eq!(set.prime_form(), [0, 1, 5]);
+/// assert_eq!(set.forte_number().to_string(), "3-4");
+/// assert_eq!(set.interval_vector(), [1, 0, 0, 1, 1, 0]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PitchClassSet {
+  mask: u16,
+}
+
+impl PitchClassSet {
+  /// Create a new [`PitchClassSet`] from integer pitch classes, reducing each
+  /// modulo twelve.
+  #[must_use]
+  pub fn new(classes: &[Semitone]) -> Self {
+    Self {
+      mask: classes
+        .iter()
+        .fold(0, |mask, class| mask | 1 << class.rem_euclid(12)),
+    }
+  }
+
+  /// Create a new [`PitchClassSet`] from [`PitchClass`]es.
+  #[must_use]
+  pub fn new_from_pitch_classes(classes: &[PitchClass]) -> Self {
+    Self::new(
+      &classes
+        .iter()
+        .map(|class| class.semitones())
+        .collect::<Vec<_>>(),
+    )
+  }
+
+  /// Create a new [`PitchClassSet`] from the [`PitchClass`]es of
+  /// [`Pitch`]es, ignoring their octaves.
+  #[must_use]
+  pub fn new_from_pitches(pitches: &[Pitch]) -> Self {
+    Self::new(
+      &pitches
+        .iter()
+        .map(|pitch| pitch.pitch_class().semitones())
+        .collect::<Vec<_>>(),
+    )
+  }
+
+  fn from_digits(digits: &str) -> Self {
+    Self::new(
+      &digits
+        .chars()
+        .filter_map(|digit| digit.to_digit(12))
+        .map(Semitone::from)
+        .collect::<Vec<_>>(),
+    )
+  }
+
+  /// Returns the integer pitch classes of the [`PitchClassSet`] in ascending
+  /// order.
+  #[must_use]
+  pub fn classes(self) -> Vec<Semitone> {
+    (0..12).filter(|&class| self.contains(class)).collect()
+  }
+
+  /// Returns the [`PitchClass`]es of the [`PitchClassSet`], spelled with
+  /// sharps.
+  #[must_use]
+  pub fn pitch_classes(self) -> Vec<PitchClass> {
+    self
+      .classes()
+      .into_iter()
+      .map(|class| NOTES[class as usize])
+      .collect()
+  }
+
+  /// Returns the number of pitch classes in the [`PitchClassSet`].
+  #[must_use]
+  pub const fn len(self) -> usize { self.mask.count_ones() as usize }
+
+  /// Returns whether the [`PitchClassSet`] is empty.
+  #[must_use]
+  pub const fn is_empty(self) -> bool { self.mask == 0 }
+
+  /// Returns whether the [`PitchClassSet`] contains an integer pitch class,
+  /// reduced modulo twelve.
+  #[must_use]
+  pub const fn contains(self, class: Semitone) -> bool {
+    self.mask & 1 << class.rem_euclid(12) != 0
+  }
+
+  /// Returns the transposition of the [`PitchClassSet`] by a number of
+  /// [`Semitone`]s, T*n*.
+  #[must_use]
+  pub fn transpose(self, semitones: Semitone) -> Self {
+    Self::new(
+      &self
+        .classes()
+        .iter()
+        .map(|class| class + semitones)
+        .collect::<Vec<_>>(),
+    )
+  }
+
+  /// Returns the inversion of the [`PitchClassSet`] followed by a
+  /// transposition by a number of [`Semitone`]s, T*n*I.
+  #[must_use]
+  pub fn invert(self, semitones: Semitone) -> Self {
+    Self::new(
+      &self
+        .classes()
+        .iter()
+        .map(|class| semitones - class)
+        .collect::<Vec<_>>(),
+    )
+  }
+
+  /// Returns the pitch classes absent from the [`PitchClassSet`].
+  #[must_use]
+  pub const fn complement(self) -> Self {
+    Self {
+      mask: !self.mask & 0xFFF,
+    }
+  }
+
+  /// Returns the rotation of the [`PitchClassSet`] which spans the smallest
+  /// interval, unwrapped so that it ascends from its first pitch class.
+  ///
+  /// Ties are broken by the smallest interval from the first pitch class to
+  /// the second, then to the third, and so on; remaining ties, which only
+  /// occur in transpositionally symmetric sets, keep the lowest first pitch
+  /// class.
+  fn packed_rotation(self) -> Vec<Semitone> {
+    let classes = self.classes();
+
+    (0..classes.len())
+      .map(|start| {
+        (0..classes.len())
+          .map(|index| {
+            classes[(start + index) % classes.len()]
+              + if start + index < classes.len() { 0 } else { 12 }
+          })
+          .collect::<Vec<_>>()
+      })
+      .min_by_key(|rotation| packing(rotation))
+      .unwrap_or_default()
+  }
+
+  /// Returns the pitch classes of the [`PitchClassSet`] in normal order: the
+  /// most compact ascending rotation.
+  #[must_use]
+  pub fn normal_order(self) -> Vec<Semitone> {
+    self
+      .packed_rotation()
+      .into_iter()
+      .map(|class| class.rem_euclid(12))
+      .collect()
+  }
+
+  /// Returns the prime form of the [`PitchClassSet`]: the more compact of the
+  /// normal orders of the set and its inversion, transposed to begin on zero.
+  #[must_use]
+  pub fn prime_form(self) -> Vec<Semitone> {
+    [self.packed_rotation(), self.invert(0).packed_rotation()]
+      .into_iter()
+      .map(|rotation| {
+        rotation
+          .iter()
+          .map(|class| class - rotation[0])
+          .collect::<Vec<_>>()
+      })
+      .min_by_key(|rotation| packing(rotation))
+      .unwrap_or_default()
+  }
+
+  /// Returns the interval-class vector of the [`PitchClassSet`]: the number
+  /// of pairs of pitch classes separated by each interval class from one to
+  /// six.
+  #[must_use]
+  pub fn interval_vector(self) -> [usize; 6] {
+    let classes = self.classes();
+    let mut vector = [0; 6];
+
+    for (index, low) in classes.iter().enumerate() {
+      for high in &classes[index + 1..] {
+        let interval = high - low;
+
+        vector[interval.min(12 - interval) as usize - 1] += 1;
+      }
+    }
+
+    vector
+  }
+
+  /// Returns whether two [`PitchClassSet`]s belong to the same set class,
+  /// i.e., whether one is a transposition or inversion of the other.
+  #[must_use]
+  pub fn is_equivalent(self, other: Self) -> bool {
+    self.prime_form() == other.prime_form()
+  }
+
+  /// Returns the prime form of the set class which shares the interval-class
+  /// vector of the [`PitchClassSet`] without being equivalent to it, if one
+  /// exists.
+  #[must_use]
+  pub fn z_related(self) -> Option<Self> {
+    match self.len() {
+      cardinality @ 3..=6 => {
+        let vector = self.interval_vector();
+
+        CATALOGUE[cardinality - 3]
+          .iter()
+          .map(|digits| Self::from_digits(digits))
+          .find(|set| {
+            set.interval_vector() == vector && !set.is_equivalent(self)
+          })
+          .map(|set| Self::new(&set.prime_form()))
+      }
+      // Complements of Z-related sets are Z-related too.
+      7..=9 => self
+        .complement()
+        .z_related()
+        .map(|set| Self::new(&set.complement().prime_form())),
+      _ => None,
+    }
+  }
+
+  /// Returns whether the [`PitchClassSet`] is Z-related to another, i.e.,
+  /// whether they share an interval-class vector without being equivalent.
+  #[must_use]
+  pub fn is_z_related(self, other: Self) -> bool {
+    self.interval_vector() == other.interval_vector()
+      && !self.is_equivalent(other)
+  }
+
+  /// Returns the [`ForteNumber`] of the set class of the [`PitchClassSet`].
+  #[must_use]
+  pub fn forte_number(self) -> ForteNumber {
+    let cardinality = self.len();
+    let ordinal = match cardinality {
+      0 | 1 | 11 | 12 => 1,
+      2 =>
+        self
+          .interval_vector()
+          .iter()
+          .position(|&count| count > 0)
+          .unwrap_or(0)
+          + 1,
+      3..=6 => {
+        let prime = self.prime_form();
+
+        CATALOGUE[cardinality - 3]
+          .iter()
+          .position(|digits| Self::from_digits(digits).prime_form() == prime)
+          .map_or(0, |index| index + 1)
+      }
+      _ => self.complement().forte_number().ordinal,
+    };
+
+    ForteNumber {
+      cardinality,
+      ordinal,
+      z: self.z_related().is_some(),
+    }
+  }
+}
+
+/// Orders rotations by span, then by their intervals from the first pitch
+/// class.
+fn packing(rotation: &[Semitone]) -> (Semitone, Vec<Semitone>) {
+  let first = rotation.first().copied().unwrap_or(0);
+
+  (
+    rotation.last().copied().unwrap_or(0) - first,
+    rotation.iter().map(|class| class - first).collect(),
+  )
+}
+
+impl fmt::Display for PitchClassSet {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{{{}}}",
+      self
+        .classes()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+    )
+  }
+}
+
+impl FromIterator<Semitone> for PitchClassSet {
+  fn from_iter<I: IntoIterator<Item = Semitone>>(iter: I) -> Self {
+    Self::new(&iter.into_iter().collect::<Vec<_>>())
+  }
+}
+
+impl FromIterator<PitchClass> for PitchClassSet {
+  fn from_iter<I: IntoIterator<Item = PitchClass>>(iter: I) -> Self {
+    iter.into_iter().map(PitchClass::semitones).collect()
+  }
+}
@@ -0,0 +1,142 @@
+// This file is part of Guitar <https://github.com/Fuwn/guitar>.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+//
+// Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::fmt;
+
+use crate::{
+  convert,
+  letter::Letter,
+  pitch_class::PitchClass,
+  unit::{Frequency, MidiNote, Octave},
+};
+
+/// The concert pitch which every [`Pitch`](crate::Pitch) is tuned relative
+/// to: an anchor note and the [`Frequency`] it sounds at.
+///
+/// A [`Reference`] is shared by a [`Fretboard`](crate::Fretboard), its
+/// [`String`](crate::string::String)s, and their frets, so changing it on
+/// the [`Fretboard`](crate::Fretboard) retunes all of them.
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::{Fretboard, Reference};
+///
+/// let mut fretboard = Fretboard::new(12);
+///
+/// fretboard.set_reference(Reference::A415);
+///
+/// let a = &fretboard.strings()[1].frets()[0];
+///
+/// assert_eq!(a.to_string(), "A2");
+/// assert!((a.frequency() - 103.75).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Reference {
+  class: PitchClass,
+  octave: Octave,
+  frequency: Frequency,
+}
+
+impl Reference {
+  /// Baroque pitch, A4 at 415 Hz.
+  pub const A415: Self = Self::new(PitchClass::natural(Letter::A), 4, 415.);
+  /// A4 at 432 Hz.
+  pub const A432: Self = Self::new(PitchClass::natural(Letter::A), 4, 432.);
+  /// Standard concert pitch, A4 at 440 Hz.
+  pub const A440: Self = Self::new(PitchClass::natural(Letter::A), 4, 440.);
+  /// A4 at 442 Hz, common among European orchestras.
+  pub const A442: Self = Self::new(PitchClass::natural(Letter::A), 4, 442.);
+
+  /// Create a new [`Reference`] which tunes a [`PitchClass`] in an
+  /// [`Octave`] to a [`Frequency`].
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{Pitch, PitchClass, Reference};
+  ///
+  /// // Scientific pitch, with C4 at 256 Hz.
+  /// let reference = Reference::new(PitchClass::from_name("C").unwrap(), 4, 256.);
+  /// let mut c = Pitch::new("C", 5);
+  ///
+  /// c.set_reference(reference);
+  ///
+  /// assert!((c.frequency() - 512.).abs() < 1e-9);
+  /// ```
+  #[must_use]
+  pub const fn new(
+    class: PitchClass,
+    octave: Octave,
+    frequency: Frequency,
+  ) -> Self {
+    Self {
+      class,
+      octave,
+      frequency,
+    }
+  }
+
+  /// Create a new [`Reference`] which tunes a MIDI note to a [`Frequency`],
+  /// spelling the anchor note with sharps.
+  #[must_use]
+  pub const fn new_from_midi_note(
+    midi_note: MidiNote,
+    frequency: Frequency,
+  ) -> Self {
+    Self::new(
+      PitchClass::from_semitones(midi_note),
+      midi_note.div_euclid(12) - 1,
+      frequency,
+    )
+  }
+
+  /// Returns the [`PitchClass`] of the anchor note.
+  #[must_use]
+  pub const fn pitch_class(self) -> PitchClass { self.class }
+
+  /// Returns the [`Octave`] of the anchor note.
+  #[must_use]
+  pub const fn octave(self) -> Octave { self.octave }
+
+  /// Returns the [`Frequency`] of the anchor note.
+  #[must_use]
+  pub const fn frequency(self) -> Frequency { self.frequency }
+
+  /// Returns the anchor note as a MIDI note.
+  #[must_use]
+  pub const fn midi_note(self) -> MidiNote {
+    convert::pitch_and_octave_to_semitones(self.class, self.octave) + 69
+  }
+
+  /// Returns the [`Reference`] with the same anchor note tuned to another
+  /// [`Frequency`].
+  #[must_use]
+  pub const fn with_frequency(self, frequency: Frequency) -> Self {
+    Self { frequency, ..self }
+  }
+}
+
+impl Default for Reference {
+  fn default() -> Self { Self::A440 }
+}
+
+impl fmt::Display for Reference {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}{} = {} Hz", self.class, self.octave, self.frequency)
+  }
+}
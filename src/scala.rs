@@ -0,0 +1,605 @@
+// This file is part of Guitar <https://github.com/Fuwn/guitar>.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+//
+// Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
+// SPDX-License-Identifier: GPL-3.0-only
+
+#![allow(
+  clippy::cast_possible_truncation,
+  clippy::cast_possible_wrap,
+  clippy::cast_precision_loss,
+  clippy::cast_sign_loss
+)]
+
+//! Reading and writing Scala scale (.scl) and keyboard mapping (.kbm) files.
+//!
+//! A [`ScalaScale`] lists the pitches of one period of a scale, and a
+//! [`KeyboardMapping`] assigns its degrees to MIDI notes and anchors one of
+//! them to a [`Frequency`]. Together, they make a [`ScalaTuning`], which is a
+//! [`Temperament`] that [`Pitch`](crate::Pitch)es and
+//! [`String`](crate::string::String)s can be tuned in.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use guitar::{
+//!   scala::{ScalaScale, ScalaTuning},
+//!   Pitch,
+//! };
+//!
+//! let scale = "! meantone.scl
+//! !
+//! Quarter-comma meantone
+//!  12
+//! !
+//!  76.04900
+//!  193.15686
+//!  310.26471
+//!  5/4
+//!  503.42157
+//!  579.47057
+//!  696.57843
+//!  25/16
+//!  889.73529
+//!  1006.84314
+//!  1082.89214
+//!  2/1
+//! "
+//! .parse::<ScalaScale>()
+//! .unwrap();
+//! let tuning = ScalaTuning::new_from_scale(scale);
+//! let c = Pitch::new("C", 4).with_temperament(tuning.clone());
+//! let e = Pitch::new("E", 4).with_temperament(tuning);
+//!
+//! assert!((e.frequency() / c.frequency() - 1.25).abs() < 1e-12);
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use crate::{
+  convert,
+  error::ScalaError,
+  pitch_class::PitchClass,
+  reference::Reference,
+  temperament::Temperament,
+  unit::{Cent, Frequency, MidiNote, Octave},
+};
+
+/// Returns the lines of a Scala file which aren't comments.
+fn lines(s: &str) -> impl Iterator<Item = &str> {
+  s.lines()
+    .map(|line| line.trim_end_matches('\r'))
+    .filter(|line| !line.starts_with('!'))
+}
+
+/// Parses the first word of the next line of a Scala file.
+fn next_value<'a, T: FromStr>(
+  lines: &mut impl Iterator<Item = &'a str>,
+  error: fn(String) -> ScalaError,
+) -> Result<T, ScalaError> {
+  let line = lines.next().ok_or(ScalaError::UnexpectedEnd)?;
+  let value = line.split_whitespace().next().unwrap_or_default();
+
+  value.parse().map_err(|_| error(value.to_string()))
+}
+
+/// A pitch of a [`ScalaScale`], written either in [`Cent`]s or as a ratio.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScalaDegree {
+  /// A number of [`Cent`]s above the first degree, written with a period
+  /// (e.g., "701.955").
+  Cents(Cent),
+  /// A frequency ratio above the first degree, written as "3/2" or "2".
+  Ratio(u64, u64),
+}
+
+impl ScalaDegree {
+  /// Returns the number of [`Cent`]s the [`ScalaDegree`] lies above the first
+  /// degree of its scale.
+  #[must_use]
+  pub fn cents(self) -> Cent {
+    match self {
+      Self::Cents(cents) => cents,
+      Self::Ratio(numerator, denominator) =>
+        convert::cents_between_frequencies(
+          numerator as Frequency,
+          denominator as Frequency,
+        ),
+    }
+  }
+}
+
+impl fmt::Display for ScalaDegree {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Cents(cents) => write!(f, "{cents:.5}"),
+      Self::Ratio(numerator, denominator) =>
+        write!(f, "{numerator}/{denominator}"),
+    }
+  }
+}
+
+impl FromStr for ScalaDegree {
+  type Err = ScalaError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let value = s.split_whitespace().next().unwrap_or_default();
+    let invalid = || ScalaError::InvalidPitch(value.to_string());
+
+    if value.contains('.') {
+      return value.parse().map(Self::Cents).map_err(|_| invalid());
+    }
+
+    let (numerator, denominator) =
+      value.split_once('/').unwrap_or((value, "1"));
+    let numerator = numerator.parse::<u64>().map_err(|_| invalid())?;
+    let denominator = denominator.parse::<u64>().map_err(|_| invalid())?;
+
+    if numerator == 0 || denominator == 0 {
+      return Err(invalid());
+    }
+
+    Ok(Self::Ratio(numerator, denominator))
+  }
+}
+
+/// A scale read from or written to a Scala scale (.scl) file: a description
+/// and the pitches of one period, the last of which is the period itself.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScalaScale {
+  description: String,
+  degrees: Vec<ScalaDegree>,
+}
+
+impl ScalaScale {
+  /// Create a new [`ScalaScale`] from a description and the pitches above its
+  /// first degree, or [`None`] if there are no pitches.
+  #[must_use]
+  pub fn new(description: &str, degrees: Vec<ScalaDegree>) -> Option<Self> {
+    (!degrees.is_empty()).then(|| Self {
+      description: description.to_string(),
+      degrees,
+    })
+  }
+
+  /// Create a new twelve-note [`ScalaScale`] from a [`Temperament`], starting
+  /// on C and repeating at the octave.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{scala::ScalaScale, temperament::WellTemperament};
+  ///
+  /// let scale = ScalaScale::new_from_temperament(
+  ///   "Werckmeister III",
+  ///   &WellTemperament::WERCKMEISTER_III,
+  /// );
+  ///
+  /// assert!((scale.cents(7) - 696.09).abs() < 0.01);
+  /// assert!(scale.to_string().contains(" 1200.00000\n"));
+  /// ```
+  #[must_use]
+  pub fn new_from_temperament<T: Temperament>(
+    description: &str,
+    temperament: &T,
+  ) -> Self {
+    let tonic = temperament.cents(PitchClass::from_semitones(0));
+
+    Self {
+      description: description.to_string(),
+      degrees: (1..=12)
+        .map(|semitones| {
+          ScalaDegree::Cents(if semitones == 12 {
+            1200.
+          } else {
+            temperament.cents(PitchClass::from_semitones(semitones)) - tonic
+          })
+        })
+        .collect(),
+    }
+  }
+
+  /// Returns the description of the [`ScalaScale`].
+  #[must_use]
+  pub fn description(&self) -> &str { &self.description }
+
+  /// Returns the pitches above the first degree, ending with the period.
+  #[must_use]
+  pub fn degrees(&self) -> &[ScalaDegree] { &self.degrees }
+
+  /// Returns the number of degrees in one period of the [`ScalaScale`].
+  #[must_use]
+  pub const fn len(&self) -> usize { self.degrees.len() }
+
+  /// Returns whether the [`ScalaScale`] has no degrees, which is never the
+  /// case for a parsed or constructed [`ScalaScale`].
+  #[must_use]
+  pub const fn is_empty(&self) -> bool { self.degrees.is_empty() }
+
+  /// Returns the interval that the [`ScalaScale`] repeats at, in [`Cent`]s.
+  #[must_use]
+  pub fn period(&self) -> Cent {
+    self.degrees.last().map_or(0., |degree| degree.cents())
+  }
+
+  /// Returns the number of [`Cent`]s that a degree lies above the first
+  /// degree, repeating at the period; the first degree is zero, and negative
+  /// degrees lie below it.
+  #[must_use]
+  pub fn cents(&self, degree: i64) -> Cent {
+    let length = self.len() as i64;
+    let step = degree.rem_euclid(length) as usize;
+    let cents = if step == 0 {
+      0.
+    } else {
+      self.degrees[step - 1].cents()
+    };
+
+    (degree.div_euclid(length) as Cent).mul_add(self.period(), cents)
+  }
+}
+
+impl fmt::Display for ScalaScale {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    writeln!(f, "!")?;
+    writeln!(f, "{}", self.description)?;
+    writeln!(f, " {}", self.degrees.len())?;
+    writeln!(f, "!")?;
+
+    for degree in &self.degrees {
+      writeln!(f, " {degree}")?;
+    }
+
+    Ok(())
+  }
+}
+
+impl FromStr for ScalaScale {
+  type Err = ScalaError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let mut lines = lines(s);
+    let description = lines.next().ok_or(ScalaError::UnexpectedEnd)?.trim();
+    let count = next_value::<usize>(&mut lines, ScalaError::InvalidNumber)?;
+    let degrees = (0..count)
+      .map(|_| lines.next().ok_or(ScalaError::UnexpectedEnd)?.parse())
+      .collect::<Result<Vec<ScalaDegree>, _>>()?;
+
+    Self::new(description, degrees).ok_or(ScalaError::EmptyScale)
+  }
+}
+
+/// A keyboard mapping read from or written to a Scala keyboard mapping
+/// (.kbm) file, which assigns the degrees of a [`ScalaScale`] to MIDI notes.
+///
+/// The mapping repeats every [`KeyboardMapping::size`] MIDI notes, starting
+/// from the middle note, which plays the first degree; each repetition
+/// climbs by the formal octave degree. A size of zero maps consecutive MIDI
+/// notes to consecutive degrees.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyboardMapping {
+  first_note: MidiNote,
+  last_note: MidiNote,
+  middle_note: MidiNote,
+  reference_note: MidiNote,
+  reference_frequency: Frequency,
+  octave_degree: usize,
+  keys: Vec<Option<usize>>,
+}
+
+impl KeyboardMapping {
+  /// Create a new [`KeyboardMapping`] which maps consecutive MIDI notes to
+  /// consecutive degrees, with the first degree on the middle note and the
+  /// reference note sounding at the reference [`Frequency`].
+  #[must_use]
+  pub const fn new_linear(
+    middle_note: MidiNote,
+    reference_note: MidiNote,
+    reference_frequency: Frequency,
+  ) -> Self {
+    Self {
+      first_note: 0,
+      last_note: 127,
+      middle_note,
+      reference_note,
+      reference_frequency,
+      octave_degree: 0,
+      keys: Vec::new(),
+    }
+  }
+
+  /// Create a new [`KeyboardMapping`] which repeats a pattern of keys, each
+  /// of which plays a degree or is unmapped ([`None`]).
+  #[must_use]
+  pub const fn new_from_keys(
+    keys: Vec<Option<usize>>,
+    middle_note: MidiNote,
+    reference_note: MidiNote,
+    reference_frequency: Frequency,
+    octave_degree: usize,
+  ) -> Self {
+    Self {
+      first_note: 0,
+      last_note: 127,
+      middle_note,
+      reference_note,
+      reference_frequency,
+      octave_degree,
+      keys,
+    }
+  }
+
+  /// Returns the number of MIDI notes before the mapping repeats, or zero for
+  /// a linear mapping.
+  #[must_use]
+  pub const fn size(&self) -> usize { self.keys.len() }
+
+  /// Returns the lowest mapped MIDI note.
+  #[must_use]
+  pub const fn first_note(&self) -> MidiNote { self.first_note }
+
+  /// Returns the highest mapped MIDI note.
+  #[must_use]
+  pub const fn last_note(&self) -> MidiNote { self.last_note }
+
+  /// Sets the range of mapped MIDI notes.
+  pub const fn set_range(&mut self, first_note: MidiNote, last_note: MidiNote) {
+    self.first_note = first_note;
+    self.last_note = last_note;
+  }
+
+  /// Returns the MIDI note which plays the first degree.
+  #[must_use]
+  pub const fn middle_note(&self) -> MidiNote { self.middle_note }
+
+  /// Returns the MIDI note which sounds at the reference [`Frequency`].
+  #[must_use]
+  pub const fn reference_note(&self) -> MidiNote { self.reference_note }
+
+  /// Returns the [`Frequency`] of the reference note.
+  #[must_use]
+  pub const fn reference_frequency(&self) -> Frequency {
+    self.reference_frequency
+  }
+
+  /// Returns the reference note and [`Frequency`] as a [`Reference`], for
+  /// tuning [`Pitch`](crate::Pitch)es in a [`ScalaTuning`] as the mapping
+  /// does.
+  #[must_use]
+  pub const fn reference(&self) -> Reference {
+    Reference::new_from_midi_note(self.reference_note, self.reference_frequency)
+  }
+
+  /// Returns the degree which each repetition of the mapping climbs by; zero
+  /// means one period of the scale.
+  #[must_use]
+  pub const fn octave_degree(&self) -> usize { self.octave_degree }
+
+  /// Returns the degree, counted from the first degree on the middle note,
+  /// which a MIDI note plays in a [`ScalaScale`] of a given length, ignoring
+  /// the range of mapped MIDI notes.
+  fn degree(&self, midi_note: MidiNote, length: usize) -> Option<i64> {
+    let distance = midi_note - self.middle_note;
+
+    if self.keys.is_empty() {
+      return Some(distance);
+    }
+
+    let size = self.keys.len() as i64;
+    let octave_degree = if self.octave_degree == 0 {
+      length
+    } else {
+      self.octave_degree
+    };
+
+    self.keys[distance.rem_euclid(size) as usize].map(|degree| {
+      distance.div_euclid(size) * octave_degree as i64 + degree as i64
+    })
+  }
+}
+
+impl Default for KeyboardMapping {
+  fn default() -> Self { Self::new_linear(60, 69, 440.) }
+}
+
+impl fmt::Display for KeyboardMapping {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    writeln!(f, "! Size of map")?;
+    writeln!(f, "{}", self.keys.len())?;
+    writeln!(f, "! First and last MIDI notes to retune")?;
+    writeln!(f, "{}", self.first_note)?;
+    writeln!(f, "{}", self.last_note)?;
+    writeln!(f, "! Middle note, where the first degree is mapped")?;
+    writeln!(f, "{}", self.middle_note)?;
+    writeln!(f, "! Reference note and frequency")?;
+    writeln!(f, "{}", self.reference_note)?;
+    writeln!(f, "{:.6}", self.reference_frequency)?;
+    writeln!(f, "! Formal octave degree")?;
+    writeln!(f, "{}", self.octave_degree)?;
+    writeln!(f, "! Mapping")?;
+
+    for key in &self.keys {
+      match key {
+        Some(degree) => writeln!(f, "{degree}")?,
+        None => writeln!(f, "x")?,
+      }
+    }
+
+    Ok(())
+  }
+}
+
+impl FromStr for KeyboardMapping {
+  type Err = ScalaError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let mut lines = lines(s).filter(|line| !line.trim().is_empty());
+    let size = next_value::<usize>(&mut lines, ScalaError::InvalidNumber)?;
+    let first_note = next_value(&mut lines, ScalaError::InvalidNumber)?;
+    let last_note = next_value(&mut lines, ScalaError::InvalidNumber)?;
+    let middle_note = next_value(&mut lines, ScalaError::InvalidNumber)?;
+    let reference_note = next_value(&mut lines, ScalaError::InvalidNumber)?;
+    let reference_frequency =
+      next_value(&mut lines, ScalaError::InvalidFrequency)?;
+    let octave_degree = next_value(&mut lines, ScalaError::InvalidNumber)?;
+    // Keys missing from the end of the mapping are unmapped.
+    let keys = (0..size)
+      .map(|_| match lines.next().map(str::trim) {
+        None | Some("x") => Ok(None),
+        Some(key) => key
+          .parse()
+          .map(Some)
+          .map_err(|_| ScalaError::InvalidNumber(key.to_string())),
+      })
+      .collect::<Result<_, _>>()?;
+
+    Ok(Self {
+      first_note,
+      last_note,
+      middle_note,
+      reference_note,
+      reference_frequency,
+      octave_degree,
+      keys,
+    })
+  }
+}
+
+/// A [`Temperament`] read from a [`ScalaScale`] and a [`KeyboardMapping`].
+///
+/// MIDI notes are numbered as in [`Pitch::midi_note`](crate::Pitch), so C4 is
+/// 60. As a [`Temperament`], a [`ScalaTuning`] is anchored to a
+/// [`Pitch`](crate::Pitch)'s [`Reference`] like any other; use
+/// [`KeyboardMapping::reference`] to tune as the mapping does. Notes which
+/// aren't mapped, or lie outside the mapped range, sound as the nearest
+/// mapped MIDI note, preferring the lower one, and have a [`Frequency`] of NaN
+/// only when no MIDI note is mapped at all.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScalaTuning {
+  scale: ScalaScale,
+  mapping: KeyboardMapping,
+}
+
+impl ScalaTuning {
+  /// Create a new [`ScalaTuning`] from a [`ScalaScale`] and a
+  /// [`KeyboardMapping`].
+  #[must_use]
+  pub const fn new(scale: ScalaScale, mapping: KeyboardMapping) -> Self {
+    Self { scale, mapping }
+  }
+
+  /// Create a new [`ScalaTuning`] from a [`ScalaScale`], with its first
+  /// degree on C4, A4 at 440 Hz, and consecutive MIDI notes mapped to
+  /// consecutive degrees.
+  #[must_use]
+  pub fn new_from_scale(scale: ScalaScale) -> Self {
+    Self::new(scale, KeyboardMapping::default())
+  }
+
+  /// Returns the [`ScalaScale`] of the [`ScalaTuning`].
+  #[must_use]
+  pub const fn scale(&self) -> &ScalaScale { &self.scale }
+
+  /// Returns the [`KeyboardMapping`] of the [`ScalaTuning`].
+  #[must_use]
+  pub const fn mapping(&self) -> &KeyboardMapping { &self.mapping }
+
+  /// Returns the number of [`Cent`]s that a MIDI note lies above the middle
+  /// note, or [`None`] if it isn't mapped.
+  fn cents_of(&self, midi_note: MidiNote) -> Option<Cent> {
+    self
+      .mapping
+      .degree(midi_note, self.scale.len())
+      .map(|degree| self.scale.cents(degree))
+  }
+
+  /// Returns the number of [`Cent`]s that the mapped MIDI note nearest to a
+  /// MIDI note lies above the middle note, or [`None`] if no MIDI note is
+  /// mapped.
+  fn nearest_cents_of(&self, midi_note: MidiNote) -> Option<Cent> {
+    let first = self.mapping.first_note;
+    let last = self.mapping.last_note;
+    let midi_note = midi_note.clamp(first, last.max(first));
+
+    (0..=last - first).find_map(|distance| {
+      [midi_note - distance, midi_note + distance]
+        .into_iter()
+        .filter(|note| (first..=last).contains(note))
+        .find_map(|note| self.cents_of(note))
+    })
+  }
+
+  /// Returns the [`Frequency`] of a MIDI note as the [`KeyboardMapping`]
+  /// tunes it, or [`None`] if it isn't mapped.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::scala::{ScalaScale, ScalaTuning};
+  ///
+  /// let tuning = ScalaTuning::new_from_scale(
+  ///   "Pentatonic\n5\n9/8\n5/4\n3/2\n5/3\n2/1"
+  ///     .parse::<ScalaScale>()
+  ///     .unwrap(),
+  /// );
+  ///
+  /// // A4 is nine MIDI notes above C4, so it plays 5/3 an octave up, and F4,
+  /// // five notes above C4, plays the octave.
+  /// assert_eq!(tuning.frequency_of(69), Some(440.));
+  /// assert!((tuning.frequency_of(60).unwrap() - 132.).abs() < 1e-9);
+  /// assert!((tuning.frequency_of(65).unwrap() - 264.).abs() < 1e-9);
+  /// ```
+  #[must_use]
+  pub fn frequency_of(&self, midi_note: MidiNote) -> Option<Frequency> {
+    if midi_note < self.mapping.first_note || midi_note > self.mapping.last_note
+    {
+      return None;
+    }
+
+    Some(convert::cents_to_frequency(
+      self.cents_of(midi_note)? - self.cents_of(self.mapping.reference_note)?,
+      self.mapping.reference_frequency,
+    ))
+  }
+}
+
+impl Temperament for ScalaTuning {
+  fn cents(&self, class: PitchClass) -> Cent {
+    match (
+      self.nearest_cents_of(60 + class.offset()),
+      self.nearest_cents_of(60),
+    ) {
+      (Some(cents), Some(middle)) => cents - middle,
+      _ => Cent::NAN,
+    }
+  }
+
+  fn frequency(
+    &self,
+    class: PitchClass,
+    octave: Octave,
+    reference: Reference,
+  ) -> Frequency {
+    let midi_note = convert::pitch_and_octave_to_semitones(class, octave) + 69;
+
+    match (
+      self.nearest_cents_of(midi_note),
+      self.nearest_cents_of(reference.midi_note()),
+    ) {
+      (Some(cents), Some(anchor)) =>
+        convert::cents_to_frequency(cents - anchor, reference.frequency()),
+      _ => Frequency::NAN,
+    }
+  }
+}
@@ -0,0 +1,486 @@
+// This file is part of Guitar <https://github.com/Fuwn/guitar>.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+//
+// Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::fmt;
+
+use crate::{
+  interval::Interval,
+  pitch::Pitch,
+  pitch_class::PitchClass,
+  transpose::{Transpose, Transposition},
+};
+
+const MAJOR: [Interval; 7] = [
+  Interval::PERFECT_UNISON,
+  Interval::MAJOR_SECOND,
+  Interval::MAJOR_THIRD,
+  Interval::PERFECT_FOURTH,
+  Interval::PERFECT_FIFTH,
+  Interval::MAJOR_SIXTH,
+  Interval::MAJOR_SEVENTH,
+];
+const HARMONIC_MINOR: [Interval; 7] = [
+  Interval::PERFECT_UNISON,
+  Interval::MAJOR_SECOND,
+  Interval::MINOR_THIRD,
+  Interval::PERFECT_FOURTH,
+  Interval::PERFECT_FIFTH,
+  Interval::MINOR_SIXTH,
+  Interval::MAJOR_SEVENTH,
+];
+const MELODIC_MINOR: [Interval; 7] = [
+  Interval::PERFECT_UNISON,
+  Interval::MAJOR_SECOND,
+  Interval::MINOR_THIRD,
+  Interval::PERFECT_FOURTH,
+  Interval::PERFECT_FIFTH,
+  Interval::MAJOR_SIXTH,
+  Interval::MAJOR_SEVENTH,
+];
+const MAJOR_PENTATONIC: [Interval; 5] = [
+  Interval::PERFECT_UNISON,
+  Interval::MAJOR_SECOND,
+  Interval::MAJOR_THIRD,
+  Interval::PERFECT_FIFTH,
+  Interval::MAJOR_SIXTH,
+];
+const MINOR_PENTATONIC: [Interval; 5] = [
+  Interval::PERFECT_UNISON,
+  Interval::MINOR_THIRD,
+  Interval::PERFECT_FOURTH,
+  Interval::PERFECT_FIFTH,
+  Interval::MINOR_SEVENTH,
+];
+const BLUES: [Interval; 6] = [
+  Interval::PERFECT_UNISON,
+  Interval::MINOR_THIRD,
+  Interval::PERFECT_FOURTH,
+  Interval::DIMINISHED_FIFTH,
+  Interval::PERFECT_FIFTH,
+  Interval::MINOR_SEVENTH,
+];
+const MAJOR_BLUES: [Interval; 6] = [
+  Interval::PERFECT_UNISON,
+  Interval::MAJOR_SECOND,
+  Interval::MINOR_THIRD,
+  Interval::MAJOR_THIRD,
+  Interval::PERFECT_FIFTH,
+  Interval::MAJOR_SIXTH,
+];
+const BEBOP_DOMINANT: [Interval; 8] = [
+  Interval::PERFECT_UNISON,
+  Interval::MAJOR_SECOND,
+  Interval::MAJOR_THIRD,
+  Interval::PERFECT_FOURTH,
+  Interval::PERFECT_FIFTH,
+  Interval::MAJOR_SIXTH,
+  Interval::MINOR_SEVENTH,
+  Interval::MAJOR_SEVENTH,
+];
+const BEBOP_MAJOR: [Interval; 8] = [
+  Interval::PERFECT_UNISON,
+  Interval::MAJOR_SECOND,
+  Interval::MAJOR_THIRD,
+  Interval::PERFECT_FOURTH,
+  Interval::PERFECT_FIFTH,
+  Interval::MINOR_SIXTH,
+  Interval::MAJOR_SIXTH,
+  Interval::MAJOR_SEVENTH,
+];
+const WHOLE_TONE: [Interval; 6] = [
+  Interval::PERFECT_UNISON,
+  Interval::MAJOR_SECOND,
+  Interval::MAJOR_THIRD,
+  Interval::AUGMENTED_FOURTH,
+  Interval::AUGMENTED_FIFTH,
+  Interval::MINOR_SEVENTH,
+];
+const DIMINISHED_WHOLE_HALF: [Interval; 8] = [
+  Interval::PERFECT_UNISON,
+  Interval::MAJOR_SECOND,
+  Interval::MINOR_THIRD,
+  Interval::PERFECT_FOURTH,
+  Interval::DIMINISHED_FIFTH,
+  Interval::MINOR_SIXTH,
+  Interval::DIMINISHED_SEVENTH,
+  Interval::MAJOR_SEVENTH,
+];
+const DIMINISHED_HALF_WHOLE: [Interval; 8] = [
+  Interval::PERFECT_UNISON,
+  Interval::MINOR_SECOND,
+  Interval::MINOR_THIRD,
+  Interval::MAJOR_THIRD,
+  Interval::AUGMENTED_FOURTH,
+  Interval::PERFECT_FIFTH,
+  Interval::MAJOR_SIXTH,
+  Interval::MINOR_SEVENTH,
+];
+const CHROMATIC: [Interval; 12] = [
+  Interval::PERFECT_UNISON,
+  Interval::MINOR_SECOND,
+  Interval::MAJOR_SECOND,
+  Interval::MINOR_THIRD,
+  Interval::MAJOR_THIRD,
+  Interval::PERFECT_FOURTH,
+  Interval::AUGMENTED_FOURTH,
+  Interval::PERFECT_FIFTH,
+  Interval::MINOR_SIXTH,
+  Interval::MAJOR_SIXTH,
+  Interval::MINOR_SEVENTH,
+  Interval::MAJOR_SEVENTH,
+];
+
+/// A built-in family of [`Scale`]s, described by its interval pattern.
+///
+/// Modes of the major, harmonic minor, and melodic minor scales are derived by
+/// rotating their parent scale, so their spelling follows from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ScaleKind {
+  Ionian,
+  Dorian,
+  Phrygian,
+  Lydian,
+  Mixolydian,
+  Aeolian,
+  Locrian,
+  HarmonicMinor,
+  LocrianNatural6,
+  IonianAugmented,
+  DorianSharp4,
+  PhrygianDominant,
+  LydianSharp2,
+  Ultralocrian,
+  MelodicMinor,
+  DorianFlat2,
+  LydianAugmented,
+  LydianDominant,
+  MixolydianFlat6,
+  LocrianNatural2,
+  Altered,
+  MajorPentatonic,
+  MinorPentatonic,
+  Blues,
+  MajorBlues,
+  BebopDominant,
+  BebopMajor,
+  WholeTone,
+  DiminishedWholeHalf,
+  DiminishedHalfWhole,
+  Chromatic,
+}
+
+impl ScaleKind {
+  /// The major scale; an alias of [`ScaleKind::Ionian`].
+  pub const MAJOR: Self = Self::Ionian;
+  /// The natural minor scale; an alias of [`ScaleKind::Aeolian`].
+  pub const MINOR: Self = Self::Aeolian;
+
+  /// Returns the [`Interval`]s of the [`ScaleKind`] above its root, in
+  /// ascending order and starting with a perfect unison.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{scale::ScaleKind, Interval};
+  ///
+  /// assert_eq!(
+  ///   ScaleKind::Dorian.intervals()[2..4],
+  ///   [Interval::MINOR_THIRD, Interval::PERFECT_FOURTH],
+  /// );
+  /// ```
+  #[must_use]
+  pub fn intervals(self) -> Vec<Interval> {
+    match self {
+      Self::Ionian => rotate(&MAJOR, 0),
+      Self::Dorian => rotate(&MAJOR, 1),
+      Self::Phrygian => rotate(&MAJOR, 2),
+      Self::Lydian => rotate(&MAJOR, 3),
+      Self::Mixolydian => rotate(&MAJOR, 4),
+      Self::Aeolian => rotate(&MAJOR, 5),
+      Self::Locrian => rotate(&MAJOR, 6),
+      Self::HarmonicMinor => rotate(&HARMONIC_MINOR, 0),
+      Self::LocrianNatural6 => rotate(&HARMONIC_MINOR, 1),
+      Self::IonianAugmented => rotate(&HARMONIC_MINOR, 2),
+      Self::DorianSharp4 => rotate(&HARMONIC_MINOR, 3),
+      Self::PhrygianDominant => rotate(&HARMONIC_MINOR, 4),
+      Self::LydianSharp2 => rotate(&HARMONIC_MINOR, 5),
+      Self::Ultralocrian => rotate(&HARMONIC_MINOR, 6),
+      Self::MelodicMinor => rotate(&MELODIC_MINOR, 0),
+      Self::DorianFlat2 => rotate(&MELODIC_MINOR, 1),
+      Self::LydianAugmented => rotate(&MELODIC_MINOR, 2),
+      Self::LydianDominant => rotate(&MELODIC_MINOR, 3),
+      Self::MixolydianFlat6 => rotate(&MELODIC_MINOR, 4),
+      Self::LocrianNatural2 => rotate(&MELODIC_MINOR, 5),
+      Self::Altered => rotate(&MELODIC_MINOR, 6),
+      Self::MajorPentatonic => MAJOR_PENTATONIC.to_vec(),
+      Self::MinorPentatonic => MINOR_PENTATONIC.to_vec(),
+      Self::Blues => BLUES.to_vec(),
+      Self::MajorBlues => MAJOR_BLUES.to_vec(),
+      Self::BebopDominant => BEBOP_DOMINANT.to_vec(),
+      Self::BebopMajor => BEBOP_MAJOR.to_vec(),
+      Self::WholeTone => WHOLE_TONE.to_vec(),
+      Self::DiminishedWholeHalf => DIMINISHED_WHOLE_HALF.to_vec(),
+      Self::DiminishedHalfWhole => DIMINISHED_HALF_WHOLE.to_vec(),
+      Self::Chromatic => CHROMATIC.to_vec(),
+    }
+  }
+
+  /// Returns the name of the [`ScaleKind`].
+  #[must_use]
+  pub const fn name(self) -> &'static str {
+    match self {
+      Self::Ionian => "Ionian",
+      Self::Dorian => "Dorian",
+      Self::Phrygian => "Phrygian",
+      Self::Lydian => "Lydian",
+      Self::Mixolydian => "Mixolydian",
+      Self::Aeolian => "Aeolian",
+      Self::Locrian => "Locrian",
+      Self::HarmonicMinor => "harmonic minor",
+      Self::LocrianNatural6 => "Locrian ♮6",
+      Self::IonianAugmented => "Ionian augmented",
+      Self::DorianSharp4 => "Dorian ♯4",
+      Self::PhrygianDominant => "Phrygian dominant",
+      Self::LydianSharp2 => "Lydian ♯2",
+      Self::Ultralocrian => "Ultralocrian",
+      Self::MelodicMinor => "melodic minor",
+      Self::DorianFlat2 => "Dorian ♭2",
+      Self::LydianAugmented => "Lydian augmented",
+      Self::LydianDominant => "Lydian dominant",
+      Self::MixolydianFlat6 => "Mixolydian ♭6",
+      Self::LocrianNatural2 => "Locrian ♮2",
+      Self::Altered => "altered",
+      Self::MajorPentatonic => "major pentatonic",
+      Self::MinorPentatonic => "minor pentatonic",
+      Self::Blues => "blues",
+      Self::MajorBlues => "major blues",
+      Self::BebopDominant => "bebop dominant",
+      Self::BebopMajor => "bebop major",
+      Self::WholeTone => "whole tone",
+      Self::DiminishedWholeHalf => "whole-half diminished",
+      Self::DiminishedHalfWhole => "half-whole diminished",
+      Self::Chromatic => "chromatic",
+    }
+  }
+}
+
+impl fmt::Display for ScaleKind {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.name())
+  }
+}
+
+/// Rotates an interval pattern so that it starts from the degree at `index`,
+/// respelling every [`Interval`] against the new root.
+fn rotate(intervals: &[Interval], index: usize) -> Vec<Interval> {
+  let root = intervals[index];
+
+  (0..intervals.len())
+    .map(|offset| {
+      let position = index + offset;
+      let interval = if position < intervals.len() {
+        intervals[position]
+      } else {
+        intervals[position - intervals.len()].compound(1)
+      };
+
+      interval - root
+    })
+    .collect()
+}
+
+/// A scale built from an interval pattern above a root [`Pitch`].
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::{scale::ScaleKind, Pitch, Scale};
+///
+/// let scale = Scale::new(Pitch::new("D", 4), ScaleKind::MAJOR);
+/// let names = scale
+///   .pitches()
+///   .take(8)
+///   .map(|pitch| pitch.to_string())
+///   .collect::<Vec<_>>();
+///
+/// assert_eq!(names, ["D4", "E4", "F#4", "G4", "A4", "B4", "C#5", "D5"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct Scale {
+  root: Pitch,
+  intervals: Vec<Interval>,
+}
+
+impl Scale {
+  /// Creates a new [`Scale`] from a root [`Pitch`] and a built-in
+  /// [`ScaleKind`].
+  #[must_use]
+  pub fn new(root: Pitch, kind: ScaleKind) -> Self {
+    Self::new_from_intervals(root, kind.intervals())
+  }
+
+  /// Creates a new [`Scale`] from a root [`Pitch`] and a custom interval
+  /// pattern.
+  ///
+  /// Compound [`Interval`]s are reduced to within an octave (see
+  /// [`Interval::simple`]) and a perfect octave is taken as the root, then
+  /// the [`Interval`]s are sorted and deduplicated, and a perfect unison is
+  /// added if it is missing.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{scale::Scale, Interval, Pitch};
+  ///
+  /// let scale = Scale::new_from_intervals(
+  ///   Pitch::new("C", 4),
+  ///   vec![Interval::MAJOR_NINTH, Interval::PERFECT_OCTAVE],
+  /// );
+  ///
+  /// assert_eq!(
+  ///   scale.intervals(),
+  ///   [Interval::PERFECT_UNISON, Interval::MAJOR_SECOND]
+  /// );
+  /// ```
+  #[must_use]
+  pub fn new_from_intervals(root: Pitch, intervals: Vec<Interval>) -> Self {
+    let mut intervals = intervals
+      .into_iter()
+      .map(|interval| match interval.simple() {
+        Interval::PERFECT_OCTAVE => Interval::PERFECT_UNISON,
+        interval => interval,
+      })
+      .collect::<Vec<_>>();
+
+    intervals.push(Interval::PERFECT_UNISON);
+    intervals.sort_by_key(|interval| (interval.semitones(), interval.number()));
+    intervals.dedup();
+
+    Self { root, intervals }
+  }
+
+  /// Returns the root [`Pitch`] of the [`Scale`].
+  #[must_use]
+  pub const fn root(&self) -> &Pitch { &self.root }
+
+  /// Returns the [`Interval`]s of the [`Scale`] above its root.
+  #[must_use]
+  pub fn intervals(&self) -> &[Interval] { &self.intervals }
+
+  /// Returns the number of degrees of the [`Scale`] within one octave.
+  #[must_use]
+  pub const fn len(&self) -> usize { self.intervals.len() }
+
+  /// Returns whether the [`Scale`] has no degrees; this is never the case,
+  /// since every [`Scale`] contains its root.
+  #[must_use]
+  pub const fn is_empty(&self) -> bool { self.intervals.is_empty() }
+
+  /// Returns the [`Pitch`] of a degree of the [`Scale`], starting from 1 for
+  /// the root; degrees past the octave continue into the next octaves.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{scale::ScaleKind, Pitch, Scale};
+  ///
+  /// let scale = Scale::new(Pitch::new("C", 4), ScaleKind::MAJOR);
+  ///
+  /// assert_eq!(scale.degree(3).unwrap().to_string(), "E4");
+  /// assert_eq!(scale.degree(9).unwrap().to_string(), "D5");
+  /// assert!(scale.degree(0).is_none());
+  /// ```
+  #[must_use]
+  #[allow(clippy::cast_possible_wrap)]
+  pub fn degree(&self, degree: usize) -> Option<Pitch> {
+    let index = degree.checked_sub(1)?;
+    let interval = self.intervals[index % self.len()]
+      .compound((index / self.len()) as crate::unit::Octave);
+
+    Some(&self.root + interval)
+  }
+
+  /// Returns the degree of a [`PitchClass`] within the [`Scale`], starting
+  /// from 1, comparing by sound rather than spelling.
+  #[must_use]
+  pub fn degree_of(&self, class: PitchClass) -> Option<usize> {
+    self
+      .pitch_classes()
+      .iter()
+      .position(|degree| degree.is_enharmonic(class))
+      .map(|index| index + 1)
+  }
+
+  /// Returns whether a [`PitchClass`] belongs to the [`Scale`], comparing by
+  /// sound rather than spelling.
+  #[must_use]
+  pub fn contains(&self, class: PitchClass) -> bool {
+    self.degree_of(class).is_some()
+  }
+
+  /// Returns the spelled [`PitchClass`]es of the [`Scale`], starting from its
+  /// root.
+  #[must_use]
+  pub fn pitch_classes(&self) -> Vec<PitchClass> {
+    self
+      .intervals
+      .iter()
+      .map(|interval| (&self.root + *interval).pitch_class())
+      .collect()
+  }
+
+  /// Returns the mode of the [`Scale`] which starts from a degree, starting
+  /// from 1 for the root.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{scale::ScaleKind, Pitch, Scale};
+  ///
+  /// let c_major = Scale::new(Pitch::new("C", 4), ScaleKind::MAJOR);
+  ///
+  /// assert_eq!(
+  ///   c_major.mode(2),
+  ///   Some(Scale::new(Pitch::new("D", 4), ScaleKind::Dorian)),
+  /// );
+  /// ```
+  #[must_use]
+  pub fn mode(&self, degree: usize) -> Option<Self> {
+    let index = degree.checked_sub(1).filter(|index| *index < self.len())?;
+
+    Some(Self {
+      root: &self.root + self.intervals[index],
+      intervals: rotate(&self.intervals, index),
+    })
+  }
+
+  /// Returns an endless, ascending iterator over the [`Pitch`]es of the
+  /// [`Scale`], starting from its root.
+  pub fn pitches(&self) -> impl Iterator<Item = Pitch> + '_ {
+    (1..).map_while(|degree| self.degree(degree))
+  }
+}
+
+impl Transpose for Scale {
+  /// Moves the root of the [`Scale`], keeping its intervals.
+  fn transposed(&self, transposition: Transposition) -> Self {
+    Self {
+      root: self.root.transposed(transposition),
+      intervals: self.intervals.clone(),
+    }
+  }
+}
@@ -15,19 +15,81 @@
 // Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
 // SPDX-License-Identifier: GPL-3.0-only
 
-use crate::{unit::Frequency, Pitch};
+#![allow(clippy::cast_precision_loss)]
 
+use std::ops::Range;
+
+use crate::{
+  error::PitchError,
+  harmonic::{Harmonic, HIGHEST_PARTIAL},
+  reference::Reference,
+  temperament::{EqualTemperament, Temperament},
+  transpose::{Transpose, Transposition},
+  unit::Frequency,
+  Pitch,
+};
+
+/// A string tuned to an open [`Pitch`], whose frets each raise it by a
+/// [`Semitone`](crate::unit::Semitone) in the open [`Pitch`]'s
+/// [`Temperament`].
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::{string::String, temperament::Pythagorean, Pitch};
+///
+/// let string = String::new(Pitch::new("A", 2).with_temperament(Pythagorean), 8);
+///
+/// // A pure fifth above the open string.
+/// assert!((string.fret(7).unwrap().frequency() - 165.).abs() < 1e-9);
+/// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
-pub struct String {
-  pitch: Pitch,
-  frets: Vec<Pitch>,
+pub struct String<T = EqualTemperament> {
+  pitch: Pitch<T>,
+  frets: Vec<Pitch<T>>,
   fret_count: usize,
-  base_frequency: Frequency,
+  start_fret: usize,
 }
 
 impl String {
+  /// The number of frets, counting the open string, of a [`String`] parsed
+  /// with [`TryFrom`]: an open string and 22 frets.
+  pub const DEFAULT_FRET_COUNT: usize = 23;
+
+  /// Creates a new [`String`] from the open pitch written in scientific or
+  /// Helmholtz pitch notation (e.g., "E2").
+  ///
+  /// # Errors
+  ///
+  /// Returns a [`PitchError`] if `pitch` can't be parsed as a [`Pitch`] or
+  /// its [`Octave`](crate::unit::Octave) is out of range; see
+  /// [`Pitch::try_from`].
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// assert!(guitar::string::String::try_new("E2", 22).is_ok());
+  /// assert!(guitar::string::String::try_new("E", 22).is_ok());
+  /// assert!(guitar::string::String::try_new("H2", 22).is_err());
+  /// ```
+  pub fn try_new(pitch: &str, fret_count: usize) -> Result<Self, PitchError> {
+    Ok(Self::new(Pitch::try_from(pitch)?, fret_count))
+  }
+}
+
+impl TryFrom<&str> for String {
+  type Error = PitchError;
+
+  /// Creates a new [`String`] with [`String::DEFAULT_FRET_COUNT`] frets from
+  /// the open pitch; see [`String::try_new`].
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    Self::try_new(value, Self::DEFAULT_FRET_COUNT)
+  }
+}
+
+impl<T: Temperament> String<T> {
   #[must_use]
-  pub fn new(pitch: Pitch, fret_count: usize) -> Self {
+  pub fn new(pitch: Pitch<T>, fret_count: usize) -> Self {
     let mut frets = vec![];
     let mut next_pitch = pitch.clone();
 
@@ -42,23 +104,96 @@ impl String {
       pitch,
       frets,
       fret_count,
-      base_frequency: 440.,
+      start_fret: 0,
     }
   }
 
+  /// Returns the [`String`] moved so that its nut sits at a fret of the
+  /// neck, like the drone string of a five-string banjo which starts at the
+  /// fifth fret.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{string::String, Pitch};
+  ///
+  /// let drone = String::new(Pitch::new("G", 4), 18).with_start_fret(5);
+  ///
+  /// assert_eq!(drone.fret_at(4), None);
+  /// assert_eq!(drone.fret_at(5).unwrap().to_string(), "G4");
+  /// assert_eq!(drone.fret_at(7).unwrap().to_string(), "A4");
+  /// assert_eq!(drone.neck_frets(), 5..23);
+  /// ```
+  #[must_use]
+  pub const fn with_start_fret(mut self, start_fret: usize) -> Self {
+    self.start_fret = start_fret;
+
+    self
+  }
+
   #[must_use]
-  pub const fn pitch(&self) -> &Pitch { &self.pitch }
+  pub const fn pitch(&self) -> &Pitch<T> { &self.pitch }
 
   #[must_use]
-  pub const fn frets(&self) -> &Vec<Pitch> { &self.frets }
+  pub const fn frets(&self) -> &Vec<Pitch<T>> { &self.frets }
+
+  /// Returns the [`Pitch`] of a fret, where 0 is the open string.
+  #[must_use]
+  pub fn fret(&self, fret: usize) -> Option<&Pitch<T>> { self.frets.get(fret) }
 
   #[must_use]
   pub const fn fret_count(&self) -> &usize { &self.fret_count }
 
+  /// Returns the fret of the neck which the [`String`]'s nut sits at.
   #[must_use]
-  pub const fn base_frequency(&self) -> &Frequency { &self.base_frequency }
+  pub const fn start_fret(&self) -> usize { self.start_fret }
+
+  /// Sets the fret of the neck which the [`String`]'s nut sits at.
+  pub const fn set_start_fret(&mut self, start_fret: usize) {
+    self.start_fret = start_fret;
+  }
 
-  pub fn set_pitch(&mut self, pitch: Pitch) {
+  /// Returns the frets of the neck which the [`String`] spans, from its nut
+  /// to its last fret.
+  #[must_use]
+  pub const fn neck_frets(&self) -> Range<usize> {
+    self.start_fret..self.start_fret + self.fret_count
+  }
+
+  /// Returns whether the [`String`] spans a fret of the neck.
+  #[must_use]
+  pub const fn spans(&self, fret: usize) -> bool {
+    fret >= self.start_fret && fret < self.start_fret + self.fret_count
+  }
+
+  /// Returns the [`Pitch`] of a fret of the neck, or [`None`] if the
+  /// [`String`] doesn't span it; see [`String::neck_frets`].
+  #[must_use]
+  pub fn fret_at(&self, fret: usize) -> Option<&Pitch<T>> {
+    self.fret(fret.checked_sub(self.start_fret)?)
+  }
+
+  /// Returns the [`Reference`] which the [`String`] and its frets are tuned
+  /// relative to.
+  #[must_use]
+  pub const fn reference(&self) -> Reference { self.pitch.reference() }
+
+  /// Sets the [`Reference`] which the [`String`] and its frets are tuned
+  /// relative to, keeping their spellings.
+  pub fn set_reference(&mut self, reference: Reference) {
+    self.pitch.set_reference(reference);
+
+    for fret in &mut self.frets {
+      fret.set_reference(reference);
+    }
+  }
+
+  #[must_use]
+  pub const fn base_frequency(&self) -> Frequency {
+    self.reference().frequency()
+  }
+
+  pub fn set_pitch(&mut self, pitch: Pitch<T>) {
     let mut next_pitch = pitch.clone();
 
     for fret in &mut self.frets {
@@ -87,10 +222,127 @@ impl String {
   }
 
   pub fn set_base_frequency(&mut self, base_frequency: Frequency) {
-    self.base_frequency = base_frequency;
+    self.set_reference(self.reference().with_frequency(base_frequency));
+  }
 
-    for fret in &mut self.frets {
-      fret.set_base_frequency(base_frequency);
+  /// Returns the [`Frequency`] of a continuous position on the [`String`],
+  /// given as a fractional fret counted from its nut, for fretless
+  /// instruments, slides, and glissandi.
+  #[must_use]
+  pub fn frequency_at_position(&self, position: f64) -> Frequency {
+    self.pitch.frequency() * (position / 12.).exp2()
+  }
+
+  /// Returns the [`Pitch`] of a continuous position on the [`String`], given
+  /// as a fractional fret counted from its nut, spelled as the nearest note
+  /// with the rest as its [`Cent`](crate::unit::Cent) offset.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{string::String, Pitch};
+  ///
+  /// let string = String::new(Pitch::new("A", 1), 1);
+  ///
+  /// assert_eq!(string.pitch_at_position(5.).to_string(), "D2");
+  /// assert_eq!(string.pitch_at_position(5.25).to_string(), "D2 +25.0¢");
+  /// assert!(
+  ///   (string
+  ///     .position_of_frequency(string.pitch_at_position(3.7).frequency())
+  ///     .unwrap()
+  ///     - 3.7)
+  ///     .abs()
+  ///     < 1e-9
+  /// );
+  /// ```
+  #[must_use]
+  pub fn pitch_at_position(&self, position: f64) -> Pitch<T> {
+    let mut pitch = self.pitch.clone();
+
+    pitch.set_frequency(self.frequency_at_position(position));
+
+    pitch
+  }
+
+  /// Returns the continuous position, as a fractional fret counted from the
+  /// [`String`]'s nut, which sounds a [`Frequency`], or [`None`] if the
+  /// [`Frequency`] is below the open [`String`].
+  #[must_use]
+  pub fn position_of_frequency(&self, frequency: Frequency) -> Option<f64> {
+    let position = 12. * (frequency / self.pitch.frequency()).log2();
+
+    (position > -1e-9).then(|| position.max(0.))
+  }
+
+  /// Returns the continuous position, as a fractional fret counted from the
+  /// [`String`]'s nut, which sounds a [`Pitch`], including its
+  /// [`Cent`](crate::unit::Cent) offset; see [`String::position_of_frequency`].
+  #[must_use]
+  pub fn position_of_pitch<U: Temperament>(
+    &self,
+    pitch: &Pitch<U>,
+  ) -> Option<f64> {
+    self.position_of_frequency(pitch.frequency())
+  }
+
+  /// Returns the [`Pitch`]es of a glissando sliding between two continuous
+  /// positions, sampled at a number of evenly spaced steps including both
+  /// ends.
+  #[must_use]
+  pub fn glissando(&self, from: f64, to: f64, steps: usize) -> Vec<Pitch<T>> {
+    let gaps = steps.saturating_sub(1).max(1) as f64;
+
+    (0..steps)
+      .map(|step| {
+        self.pitch_at_position((to - from).mul_add(step as f64 / gaps, from))
+      })
+      .collect()
+  }
+
+  /// Returns the natural [`Harmonic`] of a partial, where 1 is the open
+  /// string.
+  #[must_use]
+  pub fn harmonic(&self, partial: usize) -> Option<Harmonic<T>> {
+    self.artificial_harmonic(0, partial)
+  }
+
+  /// Returns the natural [`Harmonic`]s of partials 2 through 16.
+  #[must_use]
+  pub fn harmonics(&self) -> Vec<Harmonic<T>> {
+    (2..=HIGHEST_PARTIAL)
+      .filter_map(|partial| self.harmonic(partial))
+      .collect()
+  }
+
+  /// Returns the artificial or pinch [`Harmonic`] of a partial above a
+  /// fretted note, or [`None`] if the fret doesn't exist or the partial is
+  /// 0.
+  #[must_use]
+  pub fn artificial_harmonic(
+    &self,
+    fret: usize,
+    partial: usize,
+  ) -> Option<Harmonic<T>> {
+    (partial > 0)
+      .then(|| self.fret(fret))
+      .flatten()
+      .map(|fretted| Harmonic::new(fretted, fret, partial))
+  }
+}
+
+impl<T: Temperament> Transpose for String<T> {
+  /// Retunes the [`String`] so that its open [`Pitch`] and every fret are
+  /// transposed.
+  fn transposed(&self, transposition: Transposition) -> Self {
+    Self {
+      pitch: self.pitch.transposed(transposition),
+      frets: self
+        .frets
+        .iter()
+        .map(|fret| fret.transposed(transposition))
+        .collect(),
+      fret_count: self.fret_count,
+      start_fret: self.start_fret,
     }
   }
 }
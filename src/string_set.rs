@@ -0,0 +1,241 @@
+// This file is part of Guitar <https://github.com/Fuwn/guitar>.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+//
+// Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
+// SPDX-License-Identifier: GPL-3.0-only
+
+#![allow(clippy::cast_precision_loss)]
+
+use crate::{
+  neck::Neck,
+  tension::{Gauge, Material, Tension, TensionRating, GAUGES},
+  tuning::Tuning,
+};
+
+/// The number of ranked [`Gauge`]s kept for each string.
+const CANDIDATES: usize = 5;
+
+/// How a string set is built, which decides the [`Material`]s its strings
+/// can be made of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Construction {
+  /// Plain steel and nickel wound strings.
+  Electric,
+  /// Plain steel and phosphor bronze strings.
+  Acoustic,
+  /// Nylon trebles and silver-wound nylon basses.
+  Classical,
+  /// Plain steel and flatwound strings.
+  Flatwound,
+}
+
+impl Construction {
+  /// Returns the [`Material`]s which strings of the [`Construction`] can be
+  /// made of.
+  #[must_use]
+  pub const fn materials(self) -> &'static [Material] {
+    match self {
+      Self::Electric => &[Material::PlainSteel, Material::NickelWound],
+      Self::Acoustic => &[Material::PlainSteel, Material::PhosphorBronze],
+      Self::Classical => &[Material::Nylon, Material::SilverWoundNylon],
+      Self::Flatwound => &[Material::PlainSteel, Material::Flatwound],
+    }
+  }
+}
+
+/// The tension which each string of a set should be tuned to, in pounds.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum TensionProfile {
+  /// The same tension on every string.
+  Even(f64),
+  /// Tension stepping evenly from the first string to the last, which
+  /// evens out the feel of wound and plain strings under the hand.
+  Balanced { first: f64, last: f64 },
+  /// A tension on the treble strings which rises by a quarter towards the
+  /// first string, for a firmer bass.
+  HeavierBass(f64),
+}
+
+impl TensionProfile {
+  /// Returns the target [`Tension`] of a string out of a number of strings.
+  #[must_use]
+  pub fn target(self, string: usize, strings: usize) -> Tension {
+    let towards_last = string as f64 / strings.saturating_sub(1).max(1) as f64;
+    let (first, last) = match self {
+      Self::Even(pounds) => (pounds, pounds),
+      Self::Balanced { first, last } => (first, last),
+      Self::HeavierBass(pounds) => (pounds * 1.25, pounds),
+    };
+
+    Tension::new_from_pounds((last - first).mul_add(towards_last, first))
+  }
+}
+
+/// The [`Gauge`]s recommended for one string of a [`StringSet`], ranked by
+/// how close their [`Tension`] is to the target.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recommendation {
+  target: Tension,
+  candidates: Vec<(Gauge, Tension)>,
+}
+
+impl Recommendation {
+  /// Returns the target [`Tension`] of the string.
+  #[must_use]
+  pub const fn target(&self) -> Tension { self.target }
+
+  /// Returns the recommended [`Gauge`]s and their [`Tension`]s, closest to
+  /// the target first.
+  #[must_use]
+  pub fn candidates(&self) -> &[(Gauge, Tension)] { &self.candidates }
+
+  /// Returns the closest [`Gauge`] to the target and its [`Tension`].
+  #[must_use]
+  pub fn best(&self) -> Option<(Gauge, Tension)> {
+    self.candidates.first().copied()
+  }
+
+  /// Returns whether the closest [`Gauge`] is outside of its
+  /// [`Material::typical_tension`], or there is no [`Gauge`] at all.
+  #[must_use]
+  pub fn is_flagged(&self) -> bool {
+    self.best().is_none_or(|(gauge, tension)| {
+      tension.rating(gauge.material()) != TensionRating::Comfortable
+    })
+  }
+}
+
+/// A designed set of strings, recommending [`Gauge`]s for each string of a
+/// [`Tuning`] which follow a [`TensionProfile`].
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::{
+///   string_set::{Construction, StringSet, TensionProfile},
+///   Neck, Tuning,
+/// };
+///
+/// let set = StringSet::design(
+///   &Tuning::default(),
+///   &Neck::new_from_inches(25.5, 22),
+///   TensionProfile::Even(17.),
+///   Construction::Electric,
+/// );
+/// let gauges = set
+///   .gauges()
+///   .iter()
+///   .flatten()
+///   .map(ToString::to_string)
+///   .collect::<Vec<_>>();
+///
+/// assert_eq!(gauges[0], ".046 nickel wound");
+/// assert_eq!(gauges[5], ".010 plain steel");
+/// assert!(set.flagged().is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StringSet {
+  recommendations: Vec<Recommendation>,
+}
+
+impl StringSet {
+  /// Designs a [`StringSet`] for a [`Tuning`] over the scale lengths of a
+  /// [`Neck`], picking from the built-in [`GAUGES`] of a [`Construction`].
+  #[must_use]
+  pub fn design(
+    tuning: &Tuning,
+    neck: &Neck,
+    profile: TensionProfile,
+    construction: Construction,
+  ) -> Self {
+    let catalogue = GAUGES
+      .iter()
+      .filter(|gauge| construction.materials().contains(&gauge.material()))
+      .copied()
+      .collect::<Vec<_>>();
+
+    Self::design_from_catalogue(tuning, neck, profile, &catalogue)
+  }
+
+  /// Designs a [`StringSet`] for a [`Tuning`] over the scale lengths of a
+  /// [`Neck`], picking from a catalogue of [`Gauge`]s.
+  ///
+  /// Strings beyond the [`Neck`]'s strings use its first scale length.
+  #[must_use]
+  pub fn design_from_catalogue(
+    tuning: &Tuning,
+    neck: &Neck,
+    profile: TensionProfile,
+    catalogue: &[Gauge],
+  ) -> Self {
+    let recommendations = tuning
+      .pitches()
+      .iter()
+      .enumerate()
+      .map(|(string, pitch)| {
+        let target = profile.target(string, tuning.len());
+        let scale_length = neck
+          .scale_lengths()
+          .get(string)
+          .copied()
+          .unwrap_or_else(|| neck.scale_length());
+        let mut candidates = catalogue
+          .iter()
+          .map(|gauge| (*gauge, gauge.tension(pitch.frequency(), scale_length)))
+          .collect::<Vec<_>>();
+
+        candidates.sort_by(|(_, a), (_, b)| {
+          (a.pounds() - target.pounds())
+            .abs()
+            .total_cmp(&(b.pounds() - target.pounds()).abs())
+        });
+        candidates.truncate(CANDIDATES);
+
+        Recommendation { target, candidates }
+      })
+      .collect();
+
+    Self { recommendations }
+  }
+
+  /// Returns the [`Recommendation`] for each string, in the same order as
+  /// [`Tuning::pitches`].
+  #[must_use]
+  pub fn recommendations(&self) -> &[Recommendation] { &self.recommendations }
+
+  /// Returns the closest [`Gauge`] for each string, or [`None`] for strings
+  /// which have no [`Gauge`] at all, in the same order as
+  /// [`Tuning::pitches`].
+  #[must_use]
+  pub fn gauges(&self) -> Vec<Option<Gauge>> {
+    self
+      .recommendations
+      .iter()
+      .map(|recommendation| recommendation.best().map(|(gauge, _)| gauge))
+      .collect()
+  }
+
+  /// Returns the strings whose closest [`Gauge`] falls outside of safe
+  /// tension bounds; see [`Recommendation::is_flagged`].
+  #[must_use]
+  pub fn flagged(&self) -> Vec<usize> {
+    self
+      .recommendations
+      .iter()
+      .enumerate()
+      .filter(|(_, recommendation)| recommendation.is_flagged())
+      .map(|(string, _)| string)
+      .collect()
+  }
+}
@@ -0,0 +1,377 @@
+// This file is part of Guitar <https://github.com/Fuwn/guitar>.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+//
+// Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
+// SPDX-License-Identifier: GPL-3.0-only
+
+#![allow(
+  clippy::cast_possible_truncation,
+  clippy::cast_precision_loss,
+  clippy::cast_sign_loss
+)]
+
+use crate::{
+  convert,
+  pitch_class::PitchClass,
+  reference::Reference,
+  unit::{Cent, Frequency, Octave},
+};
+
+/// The size of a pure fifth (3:2) in [`Cent`]s.
+const PURE_FIFTH: Cent = 701.955_000_865_387_4;
+/// The amount by which twelve pure fifths exceed seven octaves, in [`Cent`]s.
+const PYTHAGOREAN_COMMA: Cent = 23.460_010_384_649_013;
+/// The amount by which four pure fifths exceed two octaves and a pure major
+/// third (81:80), in [`Cent`]s.
+const SYNTONIC_COMMA: Cent = 21.506_289_596_714_78;
+
+/// A system which decides how far each [`PitchClass`] lies above C, and so the
+/// [`Frequency`] of every pitch.
+///
+/// Every [`Temperament`] is anchored to a [`Reference`], so its anchor note
+/// always sounds at the [`Reference`]'s [`Frequency`] and other pitches are
+/// tuned relative to it.
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::{
+///   temperament::{Pythagorean, Temperament},
+///   PitchClass, Reference,
+/// };
+///
+/// let e = PitchClass::from_name("E").unwrap();
+///
+/// assert!((Pythagorean.frequency(e, 5, Reference::A440) - 660.).abs() < 1e-9);
+/// ```
+pub trait Temperament: Clone {
+  /// Returns the number of [`Cent`]s that a [`PitchClass`] lies above C in
+  /// the same octave; Cb lies below C, and B# close to the next C.
+  fn cents(&self, class: PitchClass) -> Cent;
+
+  /// Returns the [`Frequency`] of a [`PitchClass`] in an [`Octave`], tuned
+  /// relative to a [`Reference`].
+  fn frequency(
+    &self,
+    class: PitchClass,
+    octave: Octave,
+    reference: Reference,
+  ) -> Frequency {
+    convert::cents_to_frequency(
+      ((octave - reference.octave()) as Cent).mul_add(
+        1200.,
+        self.cents(class) - self.cents(reference.pitch_class()),
+      ),
+      reference.frequency(),
+    )
+  }
+}
+
+/// Places a [`PitchClass`] on a chain of fifths of one size, reduced by
+/// octaves to lie nearest its equal-tempered position.
+fn chain_of_fifths(fifth: Cent, class: PitchClass) -> Cent {
+  let cents = fifth * class.fifths() as Cent;
+  let octaves = ((cents / 100. - class.offset() as Cent) / 12.).round();
+
+  octaves.mul_add(-1200., cents)
+}
+
+/// Looks up a [`PitchClass`] in a table of twelve [`Cent`] values indexed by
+/// [`Semitone`](crate::unit::Semitone)s above C, crossing an octave for
+/// spellings like Cb and B#.
+fn from_table(table: &[Cent; 12], class: PitchClass) -> Cent {
+  ((class.offset() - class.semitones()) as Cent)
+    .mul_add(100., table[class.semitones() as usize])
+}
+
+/// An equal division of the octave into any number of steps (n-EDO).
+///
+/// Spellings follow the chain of fifths, using the division's closest
+/// approximation of a pure fifth, so C# and Db are distinct in 19-EDO or
+/// 31-EDO.
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::{
+///   temperament::{EqualTemperament, Temperament},
+///   PitchClass,
+/// };
+///
+/// let nineteen = EqualTemperament::new(19);
+/// let cents = |name| nineteen.cents(PitchClass::from_name(name).unwrap());
+///
+/// assert!((cents("C#") - 1200. / 19.).abs() < 1e-9);
+/// assert!((cents("Db") - 2400. / 19.).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EqualTemperament {
+  divisions: u32,
+}
+
+impl EqualTemperament {
+  /// Create a new [`EqualTemperament`] which divides the octave into a number
+  /// of equal steps.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `divisions` is zero, since an octave can't be divided into no
+  /// steps.
+  #[must_use]
+  pub const fn new(divisions: u32) -> Self {
+    assert!(divisions > 0, "an octave needs at least one division");
+
+    Self { divisions }
+  }
+
+  /// Returns the number of steps in an octave.
+  #[must_use]
+  pub const fn divisions(self) -> u32 { self.divisions }
+
+  /// Returns the number of steps which make up a fifth.
+  #[must_use]
+  pub fn fifth_steps(self) -> u32 {
+    (f64::from(self.divisions) * PURE_FIFTH / 1200.).round() as u32
+  }
+}
+
+impl Default for EqualTemperament {
+  fn default() -> Self { Self::new(12) }
+}
+
+impl Temperament for EqualTemperament {
+  fn cents(&self, class: PitchClass) -> Cent {
+    chain_of_fifths(
+      f64::from(self.fifth_steps()) * 1200. / f64::from(self.divisions),
+      class,
+    )
+  }
+}
+
+/// Pythagorean tuning, which stacks pure fifths, spelling sharps and flats
+/// apart by a Pythagorean comma.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Pythagorean;
+
+impl Temperament for Pythagorean {
+  fn cents(&self, class: PitchClass) -> Cent {
+    chain_of_fifths(PURE_FIFTH, class)
+  }
+}
+
+/// A meantone temperament, which narrows every fifth by a fraction of a
+/// syntonic comma.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Meantone {
+  fraction: f64,
+}
+
+impl Meantone {
+  /// Quarter-comma meantone, whose major thirds are pure.
+  pub const QUARTER_COMMA: Self = Self::new(0.25);
+
+  /// Create a new [`Meantone`] which narrows every fifth by a fraction of a
+  /// syntonic comma.
+  #[must_use]
+  pub const fn new(fraction: f64) -> Self { Self { fraction } }
+
+  /// Returns the fraction of a syntonic comma that each fifth is narrowed by.
+  #[must_use]
+  pub const fn fraction(self) -> f64 { self.fraction }
+}
+
+impl Temperament for Meantone {
+  fn cents(&self, class: PitchClass) -> Cent {
+    chain_of_fifths(self.fraction.mul_add(-SYNTONIC_COMMA, PURE_FIFTH), class)
+  }
+}
+
+/// Five-limit just intonation, with pure intervals above a chosen tonic.
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::{
+///   temperament::{JustIntonation, Temperament},
+///   PitchClass, Reference,
+/// };
+///
+/// let c = PitchClass::from_name("C").unwrap();
+/// let just = JustIntonation::new(c);
+///
+/// assert!((just.frequency(c, 4, Reference::A440) - 264.).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JustIntonation {
+  tonic: PitchClass,
+}
+
+impl JustIntonation {
+  /// The ratios above the tonic of each [`Semitone`](crate::unit::Semitone)
+  /// of the chromatic scale.
+  const RATIOS: [(u32, u32); 12] = [
+    (1, 1),
+    (16, 15),
+    (9, 8),
+    (6, 5),
+    (5, 4),
+    (4, 3),
+    (45, 32),
+    (3, 2),
+    (8, 5),
+    (5, 3),
+    (9, 5),
+    (15, 8),
+  ];
+
+  /// Create a new [`JustIntonation`] tuned above a tonic.
+  #[must_use]
+  pub const fn new(tonic: PitchClass) -> Self { Self { tonic } }
+
+  /// Returns the tonic of the [`JustIntonation`].
+  #[must_use]
+  pub const fn tonic(self) -> PitchClass { self.tonic }
+}
+
+impl Temperament for JustIntonation {
+  fn cents(&self, class: PitchClass) -> Cent {
+    let distance = class.offset() - self.tonic.offset();
+    let (numerator, denominator) =
+      Self::RATIOS[distance.rem_euclid(12) as usize];
+
+    let ratio = convert::cents_between_frequencies(
+      f64::from(numerator),
+      f64::from(denominator),
+    );
+
+    (self.tonic.offset() as Cent).mul_add(
+      100.,
+      (distance.div_euclid(12) as Cent).mul_add(1200., ratio),
+    )
+  }
+}
+
+/// A circulating well temperament, described by how much each fifth around
+/// the circle of fifths is narrowed from pure.
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::{
+///   temperament::{Temperament, WellTemperament},
+///   PitchClass,
+/// };
+///
+/// let g = PitchClass::from_name("G").unwrap();
+///
+/// assert!((WellTemperament::WERCKMEISTER_III.cents(g) - 696.09).abs() < 0.01);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct WellTemperament {
+  narrowing: [Cent; 12],
+}
+
+impl WellTemperament {
+  /// Kirnberger III (1779): C–G through A–E narrowed by a quarter of a
+  /// syntonic comma, and F#–C# by a schisma.
+  pub const KIRNBERGER_III: Self = Self::new([
+    SYNTONIC_COMMA / 4.,
+    SYNTONIC_COMMA / 4.,
+    SYNTONIC_COMMA / 4.,
+    SYNTONIC_COMMA / 4.,
+    0.,
+    0.,
+    PYTHAGOREAN_COMMA - SYNTONIC_COMMA,
+    0.,
+    0.,
+    0.,
+    0.,
+    0.,
+  ]);
+  /// Vallotti's temperament: F–C through E–B narrowed by a sixth of a
+  /// Pythagorean comma.
+  pub const VALLOTTI: Self = Self::new([
+    PYTHAGOREAN_COMMA / 6.,
+    PYTHAGOREAN_COMMA / 6.,
+    PYTHAGOREAN_COMMA / 6.,
+    PYTHAGOREAN_COMMA / 6.,
+    PYTHAGOREAN_COMMA / 6.,
+    0.,
+    0.,
+    0.,
+    0.,
+    0.,
+    0.,
+    PYTHAGOREAN_COMMA / 6.,
+  ]);
+  /// Werckmeister III (1691): C–G, G–D, D–A, and B–F# narrowed by a quarter of
+  /// a Pythagorean comma.
+  pub const WERCKMEISTER_III: Self = Self::new([
+    PYTHAGOREAN_COMMA / 4.,
+    PYTHAGOREAN_COMMA / 4.,
+    PYTHAGOREAN_COMMA / 4.,
+    0.,
+    0.,
+    PYTHAGOREAN_COMMA / 4.,
+    0.,
+    0.,
+    0.,
+    0.,
+    0.,
+    0.,
+  ]);
+  /// Thomas Young's second temperament (1799): C–G through B–F# narrowed by a
+  /// sixth of a Pythagorean comma.
+  pub const YOUNG: Self = Self::new([
+    PYTHAGOREAN_COMMA / 6.,
+    PYTHAGOREAN_COMMA / 6.,
+    PYTHAGOREAN_COMMA / 6.,
+    PYTHAGOREAN_COMMA / 6.,
+    PYTHAGOREAN_COMMA / 6.,
+    PYTHAGOREAN_COMMA / 6.,
+    0.,
+    0.,
+    0.,
+    0.,
+    0.,
+    0.,
+  ]);
+
+  /// Create a new [`WellTemperament`] from the number of [`Cent`]s that each
+  /// fifth is narrowed by, starting from C–G and ending with F–C.
+  ///
+  /// The narrowings should add up to a Pythagorean comma for the circle of
+  /// fifths to close.
+  #[must_use]
+  pub const fn new(narrowing: [Cent; 12]) -> Self { Self { narrowing } }
+
+  /// Returns the number of [`Cent`]s that each fifth is narrowed by, starting
+  /// from C–G.
+  #[must_use]
+  pub const fn narrowing(&self) -> &[Cent; 12] { &self.narrowing }
+}
+
+impl Temperament for WellTemperament {
+  fn cents(&self, class: PitchClass) -> Cent {
+    let mut table = [0.; 12];
+    let mut cents = 0.;
+
+    for (index, narrowing) in self.narrowing[..11].iter().enumerate() {
+      cents = (cents + PURE_FIFTH - narrowing).rem_euclid(1200.);
+      table[(index + 1) * 7 % 12] = cents;
+    }
+
+    from_table(&table, class)
+  }
+}
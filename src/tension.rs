@@ -0,0 +1,449 @@
+// This file is part of Guitar <https://github.com/Fuwn/guitar>.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+//
+// Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::{fmt, ops::RangeInclusive};
+
+use crate::{
+  fretboard::Fretboard,
+  neck::Neck,
+  string::String,
+  temperament::Temperament,
+  unit::{Frequency, Length},
+  utility::millimeters_to_inches,
+};
+
+/// The acceleration due to gravity in inches per second squared, which turns
+/// a unit weight in pounds per inch into a mass.
+const GRAVITY_INCHES_PER_SECOND_SQUARED: f64 = 386.088;
+/// The number of kilograms-force in a pound-force.
+const KILOGRAMS_PER_POUND: f64 = 0.453_592_37;
+/// The number of newtons in a pound-force.
+const NEWTONS_PER_POUND: f64 = 4.448_221_615_260_5;
+
+/// What a string is made of, which decides its unit weight for a given
+/// diameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Material {
+  PlainSteel,
+  NickelWound,
+  PhosphorBronze,
+  Nylon,
+  SilverWoundNylon,
+  Flatwound,
+}
+
+impl Material {
+  /// Returns a rough range of tensions in pounds which strings of the
+  /// [`Material`] are usually tuned to; below it they feel floppy, and above
+  /// it they risk breaking or damaging the instrument.
+  #[must_use]
+  pub const fn typical_tension(self) -> RangeInclusive<f64> {
+    match self {
+      Self::PlainSteel => 10. ..=30.,
+      Self::NickelWound => 10. ..=45.,
+      Self::PhosphorBronze => 15. ..=35.,
+      Self::Nylon => 10. ..=20.,
+      Self::SilverWoundNylon => 12. ..=20.,
+      Self::Flatwound => 10. ..=50.,
+    }
+  }
+}
+
+impl fmt::Display for Material {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::PlainSteel => write!(f, "plain steel"),
+      Self::NickelWound => write!(f, "nickel wound"),
+      Self::PhosphorBronze => write!(f, "phosphor bronze"),
+      Self::Nylon => write!(f, "nylon"),
+      Self::SilverWoundNylon => write!(f, "silver-wound nylon"),
+      Self::Flatwound => write!(f, "flatwound"),
+    }
+  }
+}
+
+/// A string gauge: its [`Material`], its diameter in inches, and its unit
+/// weight in pounds per inch.
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::tension::{Gauge, Material};
+///
+/// let gauge = Gauge::find(Material::PlainSteel, 0.010).unwrap();
+///
+/// // A .010 high E on a 25.5" scale.
+/// let tension = gauge.tension(329.63, 647.7);
+///
+/// assert_eq!(gauge.to_string(), ".010 plain steel");
+/// assert!((tension.pounds() - 16.2).abs() < 0.1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Gauge {
+  material: Material,
+  diameter: f64,
+  unit_weight: f64,
+}
+
+impl Gauge {
+  /// Creates a new [`Gauge`] from a [`Material`], a diameter in inches, and a
+  /// unit weight in pounds per inch.
+  #[must_use]
+  pub const fn new(
+    material: Material,
+    diameter: f64,
+    unit_weight: f64,
+  ) -> Self {
+    Self {
+      material,
+      diameter,
+      unit_weight,
+    }
+  }
+
+  /// Returns the [`Gauge`] from [`GAUGES`] of a [`Material`] nearest to a
+  /// diameter in inches, or [`None`] if none is within a quarter of a
+  /// thousandth of an inch.
+  #[must_use]
+  pub fn find(material: Material, diameter: f64) -> Option<Self> {
+    GAUGES
+      .iter()
+      .filter(|gauge| {
+        gauge.material == material
+          && (gauge.diameter - diameter).abs() <= 0.000_25
+      })
+      .min_by(|a, b| {
+        (a.diameter - diameter)
+          .abs()
+          .total_cmp(&(b.diameter - diameter).abs())
+      })
+      .copied()
+  }
+
+  /// Returns the [`Material`] of the [`Gauge`].
+  #[must_use]
+  pub const fn material(&self) -> Material { self.material }
+
+  /// Returns the diameter of the [`Gauge`] in inches.
+  #[must_use]
+  pub const fn diameter(&self) -> f64 { self.diameter }
+
+  /// Returns the unit weight of the [`Gauge`] in pounds per inch.
+  #[must_use]
+  pub const fn unit_weight(&self) -> f64 { self.unit_weight }
+
+  /// Returns the [`Tension`] of the [`Gauge`] tuned to a [`Frequency`] over a
+  /// scale length in millimetres.
+  #[must_use]
+  pub fn tension(&self, frequency: Frequency, scale_length: Length) -> Tension {
+    let wave_speed = 2. * millimeters_to_inches(scale_length) * frequency;
+
+    Tension::new_from_pounds(
+      self.unit_weight * wave_speed * wave_speed
+        / GRAVITY_INCHES_PER_SECOND_SQUARED,
+    )
+  }
+
+  /// Returns the [`Tension`] of the [`Gauge`] tuned to the open
+  /// [`Pitch`](crate::Pitch) of a [`String`] over a scale length in
+  /// millimetres.
+  #[must_use]
+  pub fn tension_of<T: Temperament>(
+    &self,
+    string: &String<T>,
+    scale_length: Length,
+  ) -> Tension {
+    self.tension(string.pitch().frequency(), scale_length)
+  }
+}
+
+impl fmt::Display for Gauge {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let precision = if (self.diameter * 10_000.).round() % 10. == 0. {
+      3
+    } else {
+      4
+    };
+    let diameter = format!("{:.precision$}", self.diameter);
+
+    write!(f, "{} {}", diameter.trim_start_matches('0'), self.material)
+  }
+}
+
+/// How a [`Tension`] compares to the [`Material::typical_tension`] of a
+/// string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TensionRating {
+  Floppy,
+  Comfortable,
+  Dangerous,
+}
+
+/// The tension of a tuned string.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Tension {
+  pounds: f64,
+}
+
+impl Tension {
+  /// Creates a new [`Tension`] from pounds-force.
+  #[must_use]
+  pub const fn new_from_pounds(pounds: f64) -> Self { Self { pounds } }
+
+  /// Returns the [`Tension`] of every [`String`] of a [`Fretboard`] strung
+  /// with a [`Gauge`] each, over the scale lengths of a [`Neck`].
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use guitar::{
+  ///   tension::{Gauge, Material, Tension, TensionRating},
+  ///   Fretboard, Neck,
+  /// };
+  ///
+  /// let gauges = [
+  ///   (Material::NickelWound, 0.046),
+  ///   (Material::NickelWound, 0.036),
+  ///   (Material::NickelWound, 0.026),
+  ///   (Material::PlainSteel, 0.017),
+  ///   (Material::PlainSteel, 0.013),
+  ///   (Material::PlainSteel, 0.010),
+  /// ]
+  /// .map(|(material, diameter)| Gauge::find(material, diameter).unwrap());
+  /// let tensions = Tension::of_fretboard(
+  ///   &Fretboard::new(22),
+  ///   &Neck::new_from_inches(25.5, 22),
+  ///   &gauges,
+  /// );
+  ///
+  /// assert!(tensions
+  ///   .iter()
+  ///   .zip(gauges)
+  ///   .all(|(tension, gauge)| tension.rating(gauge.material())
+  ///     == TensionRating::Comfortable));
+  /// ```
+  #[must_use]
+  pub fn of_fretboard(
+    fretboard: &Fretboard,
+    neck: &Neck,
+    gauges: &[Gauge],
+  ) -> Vec<Self> {
+    fretboard
+      .strings()
+      .iter()
+      .zip(neck.scale_lengths())
+      .zip(gauges)
+      .map(|((string, &scale_length), gauge)| {
+        gauge.tension_of(string, scale_length)
+      })
+      .collect()
+  }
+
+  /// Returns the [`Tension`] in pounds-force.
+  #[must_use]
+  pub const fn pounds(self) -> f64 { self.pounds }
+
+  /// Returns the [`Tension`] in kilograms-force.
+  #[must_use]
+  pub fn kilograms(self) -> f64 { self.pounds * KILOGRAMS_PER_POUND }
+
+  /// Returns the [`Tension`] in newtons.
+  #[must_use]
+  pub fn newtons(self) -> f64 { self.pounds * NEWTONS_PER_POUND }
+
+  /// Rates the [`Tension`] against the [`Material::typical_tension`] of a
+  /// [`Material`].
+  #[must_use]
+  pub fn rating(self, material: Material) -> TensionRating {
+    let typical = material.typical_tension();
+
+    if self.pounds < *typical.start() {
+      TensionRating::Floppy
+    } else if self.pounds > *typical.end() {
+      TensionRating::Dangerous
+    } else {
+      TensionRating::Comfortable
+    }
+  }
+}
+
+impl fmt::Display for Tension {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:.1} lb", self.pounds)
+  }
+}
+
+/// The built-in table of common string [`Gauge`]s, with unit weights typical
+/// of published manufacturer charts.
+pub const GAUGES: &[Gauge] = &[
+  Gauge::new(Material::PlainSteel, 0.007, 1.0854e-5),
+  Gauge::new(Material::PlainSteel, 0.008, 1.4177e-5),
+  Gauge::new(Material::PlainSteel, 0.0085, 1.6005e-5),
+  Gauge::new(Material::PlainSteel, 0.009, 1.7943e-5),
+  Gauge::new(Material::PlainSteel, 0.0095, 1.9992e-5),
+  Gauge::new(Material::PlainSteel, 0.01, 2.2152e-5),
+  Gauge::new(Material::PlainSteel, 0.0105, 2.4423e-5),
+  Gauge::new(Material::PlainSteel, 0.011, 2.6804e-5),
+  Gauge::new(Material::PlainSteel, 0.0115, 2.9296e-5),
+  Gauge::new(Material::PlainSteel, 0.012, 3.1899e-5),
+  Gauge::new(Material::PlainSteel, 0.013, 3.7437e-5),
+  Gauge::new(Material::PlainSteel, 0.0135, 4.0372e-5),
+  Gauge::new(Material::PlainSteel, 0.014, 4.3418e-5),
+  Gauge::new(Material::PlainSteel, 0.015, 4.9842e-5),
+  Gauge::new(Material::PlainSteel, 0.016, 5.6709e-5),
+  Gauge::new(Material::PlainSteel, 0.017, 6.4019e-5),
+  Gauge::new(Material::PlainSteel, 0.018, 7.1772e-5),
+  Gauge::new(Material::PlainSteel, 0.019, 7.9969e-5),
+  Gauge::new(Material::PlainSteel, 0.02, 8.8608e-5),
+  Gauge::new(Material::PlainSteel, 0.022, 1.0722e-4),
+  Gauge::new(Material::PlainSteel, 0.024, 1.2760e-4),
+  Gauge::new(Material::PlainSteel, 0.026, 1.4975e-4),
+  Gauge::new(Material::NickelWound, 0.017, 5.5240e-5),
+  Gauge::new(Material::NickelWound, 0.018, 6.2150e-5),
+  Gauge::new(Material::NickelWound, 0.019, 6.9470e-5),
+  Gauge::new(Material::NickelWound, 0.02, 7.6920e-5),
+  Gauge::new(Material::NickelWound, 0.021, 8.5430e-5),
+  Gauge::new(Material::NickelWound, 0.022, 9.2820e-5),
+  Gauge::new(Material::NickelWound, 0.024, 1.0915e-4),
+  Gauge::new(Material::NickelWound, 0.026, 1.2671e-4),
+  Gauge::new(Material::NickelWound, 0.028, 1.4666e-4),
+  Gauge::new(Material::NickelWound, 0.03, 1.6500e-4),
+  Gauge::new(Material::NickelWound, 0.032, 1.8791e-4),
+  Gauge::new(Material::NickelWound, 0.034, 2.1000e-4),
+  Gauge::new(Material::NickelWound, 0.036, 2.3964e-4),
+  Gauge::new(Material::NickelWound, 0.038, 2.6471e-4),
+  Gauge::new(Material::NickelWound, 0.039, 2.7932e-4),
+  Gauge::new(Material::NickelWound, 0.042, 3.2279e-4),
+  Gauge::new(Material::NickelWound, 0.044, 3.5182e-4),
+  Gauge::new(Material::NickelWound, 0.046, 3.8216e-4),
+  Gauge::new(Material::NickelWound, 0.048, 4.1664e-4),
+  Gauge::new(Material::NickelWound, 0.049, 4.3236e-4),
+  Gauge::new(Material::NickelWound, 0.052, 4.7909e-4),
+  Gauge::new(Material::NickelWound, 0.054, 5.1605e-4),
+  Gauge::new(Material::NickelWound, 0.056, 5.5300e-4),
+  Gauge::new(Material::NickelWound, 0.059, 6.0224e-4),
+  Gauge::new(Material::NickelWound, 0.06, 6.1530e-4),
+  Gauge::new(Material::NickelWound, 0.062, 6.5872e-4),
+  Gauge::new(Material::NickelWound, 0.064, 7.0696e-4),
+  Gauge::new(Material::NickelWound, 0.066, 7.4119e-4),
+  Gauge::new(Material::NickelWound, 0.068, 7.8709e-4),
+  Gauge::new(Material::NickelWound, 0.07, 8.2980e-4),
+  Gauge::new(Material::NickelWound, 0.072, 8.7410e-4),
+  Gauge::new(Material::NickelWound, 0.074, 9.1600e-4),
+  Gauge::new(Material::NickelWound, 0.08, 1.0600e-3),
+  Gauge::new(Material::NickelWound, 0.085, 1.1960e-3),
+  Gauge::new(Material::NickelWound, 0.09, 1.3430e-3),
+  Gauge::new(Material::NickelWound, 0.095, 1.4960e-3),
+  Gauge::new(Material::NickelWound, 0.1, 1.6540e-3),
+  Gauge::new(Material::NickelWound, 0.105, 1.8190e-3),
+  Gauge::new(Material::NickelWound, 0.11, 1.9890e-3),
+  Gauge::new(Material::NickelWound, 0.13, 2.7840e-3),
+  Gauge::new(Material::PhosphorBronze, 0.02, 7.2650e-5),
+  Gauge::new(Material::PhosphorBronze, 0.022, 8.9450e-5),
+  Gauge::new(Material::PhosphorBronze, 0.023, 9.8300e-5),
+  Gauge::new(Material::PhosphorBronze, 0.024, 1.0716e-4),
+  Gauge::new(Material::PhosphorBronze, 0.025, 1.1617e-4),
+  Gauge::new(Material::PhosphorBronze, 0.026, 1.2576e-4),
+  Gauge::new(Material::PhosphorBronze, 0.027, 1.3602e-4),
+  Gauge::new(Material::PhosphorBronze, 0.028, 1.4630e-4),
+  Gauge::new(Material::PhosphorBronze, 0.029, 1.5721e-4),
+  Gauge::new(Material::PhosphorBronze, 0.03, 1.6806e-4),
+  Gauge::new(Material::PhosphorBronze, 0.032, 1.9204e-4),
+  Gauge::new(Material::PhosphorBronze, 0.034, 2.1590e-4),
+  Gauge::new(Material::PhosphorBronze, 0.035, 2.2926e-4),
+  Gauge::new(Material::PhosphorBronze, 0.036, 2.4342e-4),
+  Gauge::new(Material::PhosphorBronze, 0.039, 2.8390e-4),
+  Gauge::new(Material::PhosphorBronze, 0.042, 3.2923e-4),
+  Gauge::new(Material::PhosphorBronze, 0.045, 3.7525e-4),
+  Gauge::new(Material::PhosphorBronze, 0.047, 4.1105e-4),
+  Gauge::new(Material::PhosphorBronze, 0.049, 4.4470e-4),
+  Gauge::new(Material::PhosphorBronze, 0.053, 5.2190e-4),
+  Gauge::new(Material::PhosphorBronze, 0.056, 5.7370e-4),
+  Gauge::new(Material::PhosphorBronze, 0.059, 6.4019e-4),
+  Gauge::new(Material::Nylon, 0.022, 1.3156e-5),
+  Gauge::new(Material::Nylon, 0.024, 1.5657e-5),
+  Gauge::new(Material::Nylon, 0.025, 1.6989e-5),
+  Gauge::new(Material::Nylon, 0.026, 1.8375e-5),
+  Gauge::new(Material::Nylon, 0.028, 2.1311e-5),
+  Gauge::new(Material::Nylon, 0.029, 2.2861e-5),
+  Gauge::new(Material::Nylon, 0.03, 2.4464e-5),
+  Gauge::new(Material::Nylon, 0.031, 2.6122e-5),
+  Gauge::new(Material::Nylon, 0.032, 2.7835e-5),
+  Gauge::new(Material::Nylon, 0.033, 2.9602e-5),
+  Gauge::new(Material::Nylon, 0.034, 3.1423e-5),
+  Gauge::new(Material::Nylon, 0.035, 3.3298e-5),
+  Gauge::new(Material::Nylon, 0.036, 3.5229e-5),
+  Gauge::new(Material::Nylon, 0.037, 3.7213e-5),
+  Gauge::new(Material::Nylon, 0.038, 3.9252e-5),
+  Gauge::new(Material::Nylon, 0.039, 4.1345e-5),
+  Gauge::new(Material::Nylon, 0.04, 4.3492e-5),
+  Gauge::new(Material::Nylon, 0.041, 4.5693e-5),
+  Gauge::new(Material::Nylon, 0.042, 4.7950e-5),
+  Gauge::new(Material::Nylon, 0.043, 5.0261e-5),
+  Gauge::new(Material::Nylon, 0.044, 5.2625e-5),
+  Gauge::new(Material::Nylon, 0.046, 5.7518e-5),
+  Gauge::new(Material::SilverWoundNylon, 0.028, 8.5551e-5),
+  Gauge::new(Material::SilverWoundNylon, 0.029, 9.5718e-5),
+  Gauge::new(Material::SilverWoundNylon, 0.03, 1.0669e-4),
+  Gauge::new(Material::SilverWoundNylon, 0.031, 1.1849e-4),
+  Gauge::new(Material::SilverWoundNylon, 0.032, 1.3116e-4),
+  Gauge::new(Material::SilverWoundNylon, 0.033, 1.4473e-4),
+  Gauge::new(Material::SilverWoundNylon, 0.034, 1.5924e-4),
+  Gauge::new(Material::SilverWoundNylon, 0.035, 1.7472e-4),
+  Gauge::new(Material::SilverWoundNylon, 0.036, 1.9120e-4),
+  Gauge::new(Material::SilverWoundNylon, 0.037, 2.0872e-4),
+  Gauge::new(Material::SilverWoundNylon, 0.038, 2.2731e-4),
+  Gauge::new(Material::SilverWoundNylon, 0.039, 2.4702e-4),
+  Gauge::new(Material::SilverWoundNylon, 0.04, 2.6786e-4),
+  Gauge::new(Material::SilverWoundNylon, 0.041, 2.8989e-4),
+  Gauge::new(Material::SilverWoundNylon, 0.042, 3.1312e-4),
+  Gauge::new(Material::SilverWoundNylon, 0.043, 3.3761e-4),
+  Gauge::new(Material::SilverWoundNylon, 0.044, 3.6339e-4),
+  Gauge::new(Material::SilverWoundNylon, 0.045, 3.9048e-4),
+  Gauge::new(Material::Flatwound, 0.02, 8.0766e-5),
+  Gauge::new(Material::Flatwound, 0.022, 9.7461e-5),
+  Gauge::new(Material::Flatwound, 0.024, 1.1461e-4),
+  Gauge::new(Material::Flatwound, 0.026, 1.3305e-4),
+  Gauge::new(Material::Flatwound, 0.028, 1.5399e-4),
+  Gauge::new(Material::Flatwound, 0.03, 1.7325e-4),
+  Gauge::new(Material::Flatwound, 0.032, 1.9731e-4),
+  Gauge::new(Material::Flatwound, 0.034, 2.2050e-4),
+  Gauge::new(Material::Flatwound, 0.036, 2.5162e-4),
+  Gauge::new(Material::Flatwound, 0.038, 2.7795e-4),
+  Gauge::new(Material::Flatwound, 0.04, 3.0850e-4),
+  Gauge::new(Material::Flatwound, 0.042, 3.3893e-4),
+  Gauge::new(Material::Flatwound, 0.044, 3.6941e-4),
+  Gauge::new(Material::Flatwound, 0.046, 4.0127e-4),
+  Gauge::new(Material::Flatwound, 0.048, 4.3747e-4),
+  Gauge::new(Material::Flatwound, 0.05, 4.7033e-4),
+  Gauge::new(Material::Flatwound, 0.052, 5.0304e-4),
+  Gauge::new(Material::Flatwound, 0.054, 5.4185e-4),
+  Gauge::new(Material::Flatwound, 0.056, 5.8065e-4),
+  Gauge::new(Material::Flatwound, 0.058, 6.1512e-4),
+  Gauge::new(Material::Flatwound, 0.06, 6.4607e-4),
+  Gauge::new(Material::Flatwound, 0.065, 7.6028e-4),
+  Gauge::new(Material::Flatwound, 0.07, 8.7129e-4),
+  Gauge::new(Material::Flatwound, 0.075, 9.8700e-4),
+  Gauge::new(Material::Flatwound, 0.08, 1.1130e-3),
+  Gauge::new(Material::Flatwound, 0.085, 1.2558e-3),
+  Gauge::new(Material::Flatwound, 0.09, 1.4101e-3),
+  Gauge::new(Material::Flatwound, 0.095, 1.5708e-3),
+  Gauge::new(Material::Flatwound, 0.1, 1.7367e-3),
+  Gauge::new(Material::Flatwound, 0.105, 1.9100e-3),
+  Gauge::new(Material::Flatwound, 0.11, 2.0884e-3),
+  Gauge::new(Material::Flatwound, 0.13, 2.9232e-3),
+];
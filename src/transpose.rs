@@ -0,0 +1,114 @@
+// This file is part of Guitar <https://github.com/Fuwn/guitar>.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+//
+// Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::{
+  interval::Interval, key::Key, pitch::Pitch, pitch_class::PitchClass,
+  temperament::Temperament, unit::Semitone,
+};
+
+/// A way of transposing pitches which keeps their spelling correct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Transposition {
+  /// Upwards by a spelled [`Interval`], so C transposed up a minor third is
+  /// Eb, never D#.
+  Up(Interval),
+  /// Downwards by a spelled [`Interval`].
+  Down(Interval),
+  /// By a signed number of [`Semitone`]s, spelling the result in a [`Key`].
+  InKey(Semitone, Key),
+}
+
+impl Transposition {
+  /// Transposes a [`Pitch`], keeping its [`Temperament`], [`Reference`], and
+  /// [`Cent`] offset.
+  ///
+  /// [`Reference`]: crate::Reference
+  /// [`Cent`]: crate::unit::Cent
+  #[must_use]
+  pub fn pitch<T: Temperament>(self, pitch: &Pitch<T>) -> Pitch<T> {
+    match self {
+      Self::Up(interval) => pitch + interval,
+      Self::Down(interval) => pitch - interval,
+      Self::InKey(semitones, key) => {
+        let midi_note = pitch.midi_note() + semitones;
+        let class = key.spell_class(PitchClass::from_semitones(midi_note));
+        let mut transposed = pitch.clone();
+
+        transposed.set_pitch_class(class);
+        transposed.set_octave((midi_note - class.offset()).div_euclid(12) - 1);
+
+        transposed
+      }
+    }
+  }
+
+  /// Transposes a [`PitchClass`].
+  #[must_use]
+  pub fn pitch_class(self, class: PitchClass) -> PitchClass {
+    self
+      .pitch(&Pitch::new_from_pitch_class(class, 4))
+      .pitch_class()
+  }
+}
+
+/// Something made of pitches which can be moved to another pitch level as a
+/// whole.
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::{transpose::Transpose, Chord, Interval, Key, PitchClass};
+///
+/// let chord = "F#m7b5/C".parse::<Chord>().unwrap();
+/// let e_flat = Key::major(PitchClass::from_name("Eb").unwrap());
+///
+/// assert_eq!(
+///   chord.transpose(Interval::MINOR_THIRD).to_string(),
+///   "Am7b5/Eb"
+/// );
+/// assert_eq!(chord.transpose_in_key(-3, e_flat).to_string(), "Ebm7b5/A");
+/// ```
+pub trait Transpose: Sized {
+  /// Returns a copy transposed by a [`Transposition`].
+  #[must_use]
+  fn transposed(&self, transposition: Transposition) -> Self;
+
+  /// Returns a copy transposed upwards by a spelled [`Interval`].
+  #[must_use]
+  fn transpose(&self, interval: Interval) -> Self {
+    self.transposed(Transposition::Up(interval))
+  }
+
+  /// Returns a copy transposed downwards by a spelled [`Interval`].
+  #[must_use]
+  fn transpose_down(&self, interval: Interval) -> Self {
+    self.transposed(Transposition::Down(interval))
+  }
+
+  /// Returns a copy transposed by a signed number of [`Semitone`]s, spelled
+  /// in a [`Key`].
+  #[must_use]
+  fn transpose_in_key(&self, semitones: Semitone, key: Key) -> Self {
+    self.transposed(Transposition::InKey(semitones, key))
+  }
+}
+
+impl<T: Temperament> Transpose for Pitch<T> {
+  fn transposed(&self, transposition: Transposition) -> Self {
+    transposition.pitch(self)
+  }
+}
@@ -0,0 +1,247 @@
+// This file is part of Guitar <https://github.com/Fuwn/guitar>.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+//
+// Copyright (C) 2022-2022 Fuwn <contact@fuwn.me>
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::{fmt, str::FromStr};
+
+use crate::{
+  accidental::Accidental,
+  error::TuningError,
+  pitch::Pitch,
+  pitch_class::PitchClass,
+  transpose::{Transpose, Transposition},
+};
+
+/// The built-in catalogue of [`Tuning`]s, as names and the open [`Pitch`]es
+/// of their strings in the same order as [`Tuning::pitches`].
+pub const PRESETS: &[(&str, &str)] = &[
+  ("Standard", "E2 A2 D3 G3 B3 E4"),
+  ("Drop D", "D2 A2 D3 G3 B3 E4"),
+  ("Drop C", "C2 G2 C3 F3 A3 D4"),
+  ("DADGAD", "D2 A2 D3 G3 A3 D4"),
+  ("Open G", "D2 G2 D3 G3 B3 D4"),
+  ("Open D", "D2 A2 D3 F#3 A3 D4"),
+  ("Open E", "E2 B2 E3 G#3 B3 E4"),
+  ("Open C", "C2 G2 C3 G3 C4 E4"),
+  ("Nashville", "E3 A3 D4 G4 B3 E4"),
+  ("All Fourths", "E2 A2 D3 G3 C4 F4"),
+  ("Major Thirds", "E2 G#2 C3 E3 G#3 C4"),
+  ("Seven-String", "B1 E2 A2 D3 G3 B3 E4"),
+  ("Eight-String", "F#1 B1 E2 A2 D3 G3 B3 E4"),
+  ("Baritone", "B1 E2 A2 D3 F#3 B3"),
+  ("Bass", "E1 A1 D2 G2"),
+  ("Five-String Bass", "B0 E1 A1 D2 G2"),
+  ("Six-String Bass", "B0 E1 A1 D2 G2 C3"),
+  ("Ukulele", "G4 C4 E4 A4"),
+  ("Low-G Ukulele", "G3 C4 E4 A4"),
+  ("Mandolin", "G3 D4 A4 E5"),
+  ("Banjo", "G4 D3 G3 B3 D4"),
+  ("Violin", "G3 D4 A4 E5"),
+  ("Viola", "C3 G3 D4 A4"),
+  ("Cello", "C2 G2 D3 A3"),
+  ("Double Bass", "E1 A1 D2 G2"),
+];
+
+/// The open [`Pitch`]es of an instrument's strings, in the order they lie
+/// across the neck starting from the bass side.
+///
+/// # Examples
+///
+/// ```rust
+/// use guitar::Tuning;
+///
+/// let dadgad = "DADGAD".parse::<Tuning>().unwrap();
+///
+/// assert_eq!(dadgad, Tuning::named("dadgad").unwrap());
+/// assert_eq!(dadgad.to_string(), "D2 A2 D3 G3 A3 D4");
+/// assert_eq!(Tuning::default().to_string(), "E2 A2 D3 G3 B3 E4");
+/// ```
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct Tuning {
+  pitches: Vec<Pitch>,
+}
+
+impl Tuning {
+  /// Creates a new [`Tuning`] from the open [`Pitch`]es of its strings.
+  #[must_use]
+  pub const fn new(pitches: Vec<Pitch>) -> Self { Self { pitches } }
+
+  /// Returns the [`Tuning`] from [`PRESETS`] with a name, ignoring case,
+  /// spaces, and hyphens (e.g., "drop d" or "low-g ukulele").
+  #[must_use]
+  pub fn named(name: &str) -> Option<Self> {
+    let name = normalise(name);
+
+    PRESETS
+      .iter()
+      .find(|(preset, _)| normalise(preset) == name)
+      .and_then(|(_, pitches)| Self::new_from_pitches(pitches).ok())
+  }
+
+  /// Returns every [`Tuning`] in [`PRESETS`] alongside its name.
+  pub fn presets() -> impl Iterator<Item = (&'static str, Self)> {
+    PRESETS.iter().filter_map(|(name, pitches)| {
+      Self::new_from_pitches(pitches)
+        .ok()
+        .map(|tuning| (*name, tuning))
+    })
+  }
+
+  /// Returns the open [`Pitch`]es of the [`Tuning`]'s strings.
+  #[must_use]
+  pub fn pitches(&self) -> &[Pitch] { &self.pitches }
+
+  /// Returns the number of strings of the [`Tuning`].
+  #[must_use]
+  pub const fn len(&self) -> usize { self.pitches.len() }
+
+  /// Returns whether the [`Tuning`] has no strings.
+  #[must_use]
+  pub const fn is_empty(&self) -> bool { self.pitches.is_empty() }
+
+  /// Parses whitespace-separated pitches in scientific or Helmholtz pitch
+  /// notation (e.g., "E2 A2 D3 G3 B3 E4" or "E A d g b e'").
+  fn new_from_pitches(s: &str) -> Result<Self, TuningError> {
+    s.split_whitespace()
+      .map(|pitch| pitch.parse().map_err(TuningError::InvalidPitch))
+      .collect::<Result<Vec<_>, _>>()
+      .map(Self::new)
+  }
+
+  /// Parses run-together pitch classes (e.g., "DADGAD" or "`DbGbBbEb`").
+  ///
+  /// The octaves come from the first of [`PRESETS`] with the same pitch
+  /// classes (e.g., "BEADGBE" is the seven-string tuning starting on B1, and
+  /// "GCEA" the re-entrant ukulele tuning); otherwise, the first string is
+  /// placed in octave 2 and each of the rest as the nearest pitch above the
+  /// one before it.
+  fn new_from_pitch_classes(s: &str) -> Result<Self, TuningError> {
+    let mut classes = vec![];
+    let mut names =
+      s.char_indices().filter(|(_, c)| !Accidental::is_symbol(*c));
+    let mut start = names.next().map_or(s.len(), |(index, _)| index);
+
+    while start < s.len() {
+      let end = names.next().map_or(s.len(), |(index, _)| index);
+
+      classes.push(
+        s[start..end]
+          .parse::<PitchClass>()
+          .map_err(TuningError::InvalidPitch)?,
+      );
+
+      start = end;
+    }
+
+    if let Some((_, preset)) = Self::presets().find(|(_, preset)| {
+      preset
+        .pitches
+        .iter()
+        .map(Pitch::pitch_class)
+        .eq(classes.iter().copied())
+    }) {
+      return Ok(preset);
+    }
+
+    let mut pitches: Vec<Pitch> = vec![];
+
+    for class in classes {
+      let pitch = pitches.last().map_or_else(
+        || Pitch::new_from_pitch_class(class, 2),
+        |previous| {
+          let mut pitch =
+            Pitch::new_from_pitch_class(class, previous.octave() - 1);
+
+          while pitch.midi_note() <= previous.midi_note() {
+            pitch.set_octave(pitch.octave() + 1);
+          }
+
+          pitch
+        },
+      );
+
+      pitches.push(pitch);
+    }
+
+    Ok(Self::new(pitches))
+  }
+}
+
+impl Default for Tuning {
+  /// Standard tuning for a six-string guitar (E2, A2, D3, G3, B3, E4).
+  fn default() -> Self {
+    Self::named("Standard").unwrap_or_else(|| Self::new(vec![]))
+  }
+}
+
+impl fmt::Display for Tuning {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for (index, pitch) in self.pitches.iter().enumerate() {
+      if index > 0 {
+        write!(f, " ")?;
+      }
+
+      write!(f, "{pitch}")?;
+    }
+
+    Ok(())
+  }
+}
+
+impl FromStr for Tuning {
+  type Err = TuningError;
+
+  /// Parses a [`Tuning`] from the name of a preset (e.g., "Open G"),
+  /// whitespace-separated pitches (e.g., "E2 A2 D3 G3 B3 E4"), or run-together
+  /// pitch classes (e.g., "DADGAD").
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    if s.trim().is_empty() {
+      return Err(TuningError::Empty);
+    }
+
+    if let Some(tuning) = Self::named(s) {
+      return Ok(tuning);
+    }
+
+    if s.trim().chars().all(|character| {
+      character.is_ascii_uppercase() || Accidental::is_symbol(character)
+    }) {
+      Self::new_from_pitch_classes(s.trim())
+    } else {
+      Self::new_from_pitches(s)
+    }
+  }
+}
+
+impl Transpose for Tuning {
+  fn transposed(&self, transposition: Transposition) -> Self {
+    Self::new(
+      self
+        .pitches
+        .iter()
+        .map(|pitch| pitch.transposed(transposition))
+        .collect(),
+    )
+  }
+}
+
+fn normalise(name: &str) -> std::string::String {
+  name
+    .chars()
+    .filter(char::is_ascii_alphanumeric)
+    .map(|character| character.to_ascii_lowercase())
+    .collect()
+}
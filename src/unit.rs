@@ -30,3 +30,7 @@ pub type MidiNote = i64;
 pub type Cent = f64;
 /// The internal data type of a count of frets.
 pub type Frets = usize;
+/// The internal data type of the number of an interval (e.g., 3 for a third).
+pub type IntervalNumber = i64;
+/// The internal data type of a length, in millimetres.
+pub type Length = f64;
@@ -34,6 +34,19 @@ pub fn capitalize_first_letter(string: &str) -> String {
 #[must_use]
 pub fn centimeters_to_inches(centimeters: f64) -> f64 { centimeters / 2.54 }
 
+#[must_use]
+pub fn inches_to_centimeters(inches: f64) -> f64 { inches * 2.54 }
+
+#[must_use]
+pub fn millimeters_to_inches(millimeters: f64) -> f64 {
+  centimeters_to_inches(millimeters / 10.0)
+}
+
+#[must_use]
+pub fn inches_to_millimeters(inches: f64) -> f64 {
+  inches_to_centimeters(inches) * 10.0
+}
+
 #[must_use]
 pub fn frequency_to_wavelength_cm(frequency: f64) -> f64 {
   SPEED_OF_SOUND_METRES_PER_SECOND / frequency * 100.0
@@ -0,0 +1,121 @@
+use guitar::{
+  analysis::{self, HarmonicFunction, NumeralKind},
+  Chord, Key, PitchClass,
+};
+
+fn key(tonic: &str, major: bool) -> Key {
+  let tonic = tonic.parse::<PitchClass>().unwrap();
+
+  if major {
+    Key::major(tonic)
+  } else {
+    Key::minor(tonic)
+  }
+}
+
+fn labels(key: Key, symbols: &[&str]) -> Vec<String> {
+  let chords = symbols
+    .iter()
+    .map(|symbol| symbol.parse::<Chord>().unwrap())
+    .collect::<Vec<_>>();
+
+  analysis::analyze_progression(key, &chords)
+    .iter()
+    .map(ToString::to_string)
+    .collect()
+}
+
+#[test]
+fn labels_diatonic_chords() {
+  assert_eq!(
+    labels(
+      key("G", true),
+      &["G", "Am7", "Bm", "Cmaj7", "D7", "Em", "F#ø7"]
+    ),
+    ["I", "ii7", "iii", "IVM7", "V7", "vi", "viiø7"]
+  );
+  assert_eq!(
+    labels(key("A", false), &["Am", "Dm", "E7", "G#°7", "C"]),
+    ["i", "iv", "V7", "vii°7", "III"]
+  );
+  assert_eq!(
+    labels(key("C", true), &["C/E", "G/D", "G7/B", "G7/F"]),
+    ["I6", "V64", "V65", "V42"]
+  );
+}
+
+#[test]
+fn labels_secondary_and_borrowed_chords() {
+  let c_major = key("C", true);
+  let chords = ["D7", "A7", "E", "C7", "C#°7", "Bb", "Ab", "Fm"]
+    .map(|symbol| symbol.parse::<Chord>().unwrap());
+  let numerals = analysis::analyze_progression(c_major, &chords);
+
+  assert_eq!(
+    numerals.iter().map(ToString::to_string).collect::<Vec<_>>(),
+    [
+      "V7/V",
+      "V7/ii",
+      "V/vi",
+      "V7/IV",
+      "vii°7/ii",
+      "bVII",
+      "bVI",
+      "iv"
+    ]
+  );
+  assert!(numerals[..5]
+    .iter()
+    .all(analysis::RomanNumeral::is_secondary));
+  assert!(numerals[5..]
+    .iter()
+    .all(analysis::RomanNumeral::is_borrowed));
+  assert_eq!(numerals[7].function(), Some(HarmonicFunction::Predominant));
+}
+
+#[test]
+fn labels_chromatic_predominants() {
+  let c_major = key("C", true);
+  let chords = ["Db/F", "Abadd#6no5", "Abadd#11add#6no5", "Abadd#6"]
+    .map(|symbol| symbol.parse::<Chord>().unwrap());
+  let numerals = analysis::analyze_progression(c_major, &chords);
+
+  assert_eq!(
+    numerals.iter().map(ToString::to_string).collect::<Vec<_>>(),
+    ["N6", "It+6", "Fr+6", "Ger+6"]
+  );
+  assert_eq!(numerals[0].kind(), NumeralKind::Neapolitan);
+  assert!(numerals
+    .iter()
+    .all(|numeral| numeral.function() == Some(HarmonicFunction::Predominant)));
+  // The same tones spelled as a dominant seventh are not an augmented sixth.
+  let dominants = ["Ab7", "Ab7/C"].map(|symbol| symbol.parse().unwrap());
+
+  assert!(analysis::analyze_progression(c_major, &dominants)
+    .iter()
+    .all(|numeral| numeral.kind() != NumeralKind::AugmentedSixth));
+  assert_eq!(labels(c_major, &["Ab7", "Ab7/C"]), ["bVI7", "bVI65"]);
+}
+
+#[test]
+fn tags_functions() {
+  let numerals = analysis::analyze_progression(
+    key("F", true),
+    &["F", "Gm", "C7", "Dm", "E°"].map(|symbol| symbol.parse().unwrap()),
+  );
+
+  assert_eq!(
+    numerals
+      .iter()
+      .map(analysis::RomanNumeral::function)
+      .collect::<Vec<_>>(),
+    [
+      Some(HarmonicFunction::Tonic),
+      Some(HarmonicFunction::Predominant),
+      Some(HarmonicFunction::Dominant),
+      Some(HarmonicFunction::Tonic),
+      Some(HarmonicFunction::Dominant),
+    ]
+  );
+  assert_eq!(numerals[2].degree(), 5);
+}
@@ -0,0 +1,157 @@
+use guitar::{chord::ChordFunction, Chord, ChordError};
+
+fn spell(symbol: &str) -> Vec<String> {
+  symbol
+    .parse::<Chord>()
+    .unwrap()
+    .pitch_classes()
+    .iter()
+    .map(ToString::to_string)
+    .collect()
+}
+
+#[test]
+fn spells_chord_symbols() {
+  assert_eq!(spell("C"), ["C", "E", "G"]);
+  assert_eq!(spell("A°"), ["A", "C", "Eb"]);
+  assert_eq!(spell("Dø7"), ["D", "F", "Ab", "C"]);
+  assert_eq!(spell("F#m7b5"), ["F#", "A", "C", "E"]);
+  assert_eq!(spell("Cmaj7#11/E"), ["C", "E", "G", "B", "F#"]);
+  assert_eq!(spell("G13sus4"), ["G", "C", "D", "F", "A", "E"]);
+  assert_eq!(spell("Bbadd9"), ["Bb", "D", "F", "C"]);
+  assert_eq!(spell("E7alt"), ["E", "G#", "D", "F", "F##", "A#", "C"]);
+  assert_eq!(spell("C6/9"), ["C", "E", "G", "A", "D"]);
+  assert_eq!(spell("Ebm(maj7)"), ["Eb", "Gb", "Bb", "D"]);
+  assert_eq!(spell("Bdim7"), ["B", "D", "F", "Ab"]);
+  assert_eq!(spell("D+"), ["D", "F#", "A#"]);
+  assert_eq!(spell("E5"), ["E", "B"]);
+  assert_eq!(spell("Am11"), ["A", "C", "E", "G", "B", "D"]);
+  assert_eq!(spell("C7(b9,#9)"), ["C", "E", "G", "Bb", "Db", "D#"]);
+  assert_eq!(spell("CΔ"), ["C", "E", "G", "B"]);
+  assert_eq!(spell("C7no3"), ["C", "G", "Bb"]);
+  assert_eq!(spell("Comit5"), ["C", "E"]);
+  assert_eq!(spell("Co7"), ["C", "Eb", "Gb", "Bbb"]);
+}
+
+#[test]
+fn slash_chords_and_functions() {
+  let chord = "Cmaj7#11/E".parse::<Chord>().unwrap();
+
+  assert_eq!(chord.to_string(), "Cmaj7#11/E");
+  assert_eq!(chord.suffix(), "maj7#11");
+  assert_eq!(
+    chord.bass().map(|bass| bass.to_string()).as_deref(),
+    Some("E")
+  );
+  assert_eq!(chord.bass_pitch(4).to_string(), "E3");
+  assert_eq!(
+    chord
+      .tones(4)
+      .iter()
+      .map(guitar::chord::ChordTone::function)
+      .collect::<Vec<_>>(),
+    [
+      ChordFunction::Root,
+      ChordFunction::Third,
+      ChordFunction::Fifth,
+      ChordFunction::Seventh,
+      ChordFunction::Eleventh,
+    ]
+  );
+  assert_eq!(
+    "G".parse::<Chord>().unwrap().bass_pitch(2).to_string(),
+    "G2"
+  );
+}
+
+#[test]
+fn rejects_malformed_symbols() {
+  assert_eq!("".parse::<Chord>(), Err(ChordError::Empty));
+  assert!(matches!(
+    "H7".parse::<Chord>(),
+    Err(ChordError::InvalidRoot(_))
+  ));
+  assert!(matches!(
+    "C/H".parse::<Chord>(),
+    Err(ChordError::InvalidBass(_))
+  ));
+  assert!(matches!(
+    "Cfoo".parse::<Chord>(),
+    Err(ChordError::UnknownModifier(_))
+  ));
+  assert!(matches!(
+    "C8".parse::<Chord>(),
+    Err(ChordError::UnknownModifier(_))
+  ));
+}
+
+fn names(pitches: &[&str]) -> Vec<String> {
+  let pitches = pitches
+    .iter()
+    .map(|name| name.parse::<guitar::Pitch>().unwrap())
+    .collect::<Vec<_>>();
+
+  Chord::identify(&pitches)
+    .iter()
+    .map(|found| found.chord().to_string())
+    .collect()
+}
+
+#[test]
+fn identifies_pitch_sets() {
+  assert_eq!(names(&["C4", "E4", "G4"])[0], "C");
+  assert_eq!(names(&["G3", "C4", "E4"])[0], "C/G");
+  assert_eq!(names(&["Bb3", "D4", "F4", "Ab4"])[0], "Bb7");
+  assert_eq!(
+    names(&["C4", "Eb4", "Gb4", "A4"])[..4],
+    ["C°7", "Eb°7/C", "Gb°7/C", "A°7/C"]
+  );
+  assert!(names(&["C4", "E4", "G4", "A4"]).contains(&"Am7/C".to_string()));
+  assert!(names(&["C4"]).is_empty());
+  assert!(names(&[]).is_empty());
+}
+
+#[test]
+fn identifies_omitted_fifths_and_rootless_voicings() {
+  let pitches = ["C3", "E3", "Bb3"].map(|name| name.parse().unwrap());
+  let matches = Chord::identify(&pitches);
+
+  assert_eq!(matches[0].chord().to_string(), "C7");
+  assert_eq!(matches[0].omitted(), [ChordFunction::Fifth]);
+
+  let pitches = ["E3", "Bb3", "D4", "G4"].map(|name| name.parse().unwrap());
+  let matches = Chord::identify(&pitches);
+  let rootless = matches.iter().find(|found| found.is_rootless()).unwrap();
+
+  assert_eq!(matches[0].chord().to_string(), "Eø7");
+  assert_eq!(rootless.chord().to_string(), "C9");
+  assert_eq!(rootless.bass_function(), ChordFunction::Third);
+}
+
+#[test]
+fn identifies_fretboard_shapes() {
+  let fretboard = guitar::Fretboard::new(13);
+  let name =
+    |shape: &[Option<usize>]| fretboard.identify(shape)[0].chord().to_string();
+
+  assert_eq!(
+    name(&[Some(3), Some(2), Some(0), Some(0), Some(0), Some(3)]),
+    "G"
+  );
+  assert_eq!(
+    name(&[None, Some(3), Some(2), Some(0), Some(1), Some(0)]),
+    "C"
+  );
+  assert_eq!(
+    name(&[Some(0), Some(2), Some(2), Some(1), Some(0), Some(0)]),
+    "E"
+  );
+  assert_eq!(
+    name(&[None, None, Some(0), Some(2), Some(1), Some(2)]),
+    "D7"
+  );
+  assert_eq!(
+    name(&[Some(0), Some(3), Some(2), Some(0), Some(1), Some(0)]),
+    "C/E"
+  );
+}
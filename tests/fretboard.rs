@@ -1,6 +1,189 @@
-use guitar;
+#[test]
+fn standard_tuning_open_strings() {
+  let fretboard = guitar::Fretboard::new(13);
+  let open = fretboard
+    .strings()
+    .iter()
+    .map(|string| string.pitch().pitch_class().to_string())
+    .collect::<Vec<_>>();
+
+  assert_eq!(open, ["E", "A", "D", "G", "B", "E"]);
+}
+
+#[test]
+fn twelfth_fret_is_an_octave() {
+  let fretboard = guitar::Fretboard::new(13);
+
+  for string in fretboard.strings() {
+    let open = &string.frets()[0];
+    let twelfth = &string.frets()[12];
+
+    assert_eq!(twelfth.pitch_class(), open.pitch_class());
+    assert_eq!(twelfth.octave(), open.octave() + 1);
+  }
+}
+
+#[test]
+fn reference_retunes_every_fret() {
+  let mut fretboard = guitar::Fretboard::new(13);
+  let before = fretboard.strings()[0].frets()[5].frequency();
+
+  fretboard.set_reference(guitar::Reference::A415);
+
+  assert_eq!(fretboard.reference(), guitar::Reference::A415);
+
+  for string in fretboard.strings() {
+    assert_eq!(string.reference(), guitar::Reference::A415);
+    assert!(string
+      .frets()
+      .iter()
+      .all(|fret| fret.base_frequency() == 415.));
+  }
+
+  let fifth = &fretboard.strings()[0].frets()[5];
+
+  assert_eq!(fifth.to_string(), "A2");
+  assert!((fifth.frequency() - 103.75).abs() < 1e-9);
+  assert!((fifth.frequency() / before - 415. / 440.).abs() < 1e-12);
+}
+
+#[test]
+fn finds_positions_of_enharmonics() {
+  let fretboard = guitar::Fretboard::new(13);
+  let e = "Fb4".parse::<guitar::Pitch>().unwrap();
+  let g_flat = guitar::PitchClass::from_name("Gb").unwrap();
+
+  assert_eq!(fretboard.positions_of(&e, ..), [(3, 9), (4, 5), (5, 0)]);
+  assert_eq!(fretboard.positions_of(&e, 1..=8), [(4, 5)]);
+  assert_eq!(
+    fretboard.positions_of_class(g_flat, ..),
+    [(0, 2), (1, 9), (2, 4), (3, 11), (4, 7), (5, 2)]
+  );
+
+  for (string, fret) in fretboard.positions_of_class(g_flat, ..) {
+    let pitch = &fretboard.strings()[string].frets()[fret];
+
+    assert_eq!(pitch.pitch_class().to_string(), "F#");
+  }
+}
+
+#[test]
+fn capos_move_the_nut() {
+  let mut fretboard = guitar::Fretboard::new(13);
+  let open_d = [None, None, Some(0), Some(2), Some(3), Some(2)];
+
+  fretboard.set_capo(Some(guitar::Capo::new(2))).unwrap();
+
+  assert_eq!(fretboard.identify(&open_d)[0].chord().to_string(), "E");
+  assert_eq!(fretboard.playable_frets(0), Some(0..=10));
+  assert_eq!(fretboard.relative_fret(0, 1), None);
+  assert_eq!(fretboard.relative_fret(0, 7), Some(5));
+  assert_eq!(fretboard.absolute_fret(0, 5), 7);
+  assert_eq!(
+    fretboard.positions_of(&"E3".parse().unwrap(), ..),
+    [(0, 10), (1, 5), (2, 0)]
+  );
+
+  fretboard
+    .set_capo(Some(guitar::Capo::new_partial(2, &[1, 2, 3, 4, 5])))
+    .unwrap();
+
+  assert_eq!(fretboard.playable_frets(0), Some(0..=12));
+  assert_eq!(fretboard.pitch_at(0, 0).unwrap().to_string(), "E2");
+  assert_eq!(fretboard.pitch_at(1, 0).unwrap().to_string(), "B2");
+  assert_eq!(
+    fretboard.identify(&[Some(0), Some(0), Some(0), Some(2), Some(3), Some(2)])
+      [0]
+      .chord()
+      .to_string(),
+    "E"
+  );
+
+  fretboard.set_capo(None).unwrap();
+
+  assert_eq!(fretboard.sounding_tuning(), fretboard.tuning());
+
+  fretboard.set_capo(Some(guitar::Capo::new(14))).unwrap();
+
+  assert_eq!(fretboard.sounding_tuning().len(), 6);
+  assert_eq!(fretboard.sounding_tuning().pitches()[0].to_string(), "F#3");
+}
+
+#[test]
+fn capos_cover_existing_strings() {
+  let mut fretboard = guitar::Fretboard::new(13);
+
+  assert_eq!(
+    fretboard.set_capo(Some(guitar::Capo::new_partial(2, &[5, 6]))),
+    Err(guitar::FretboardError::CapoStringOutOfRange {
+      string: 6,
+      strings: 6
+    })
+  );
+  assert_eq!(fretboard.capo(), None);
+  assert_eq!(
+    fretboard
+      .with_tuning(&"D2 A2 D3 G3".parse().unwrap())
+      .capo(),
+    None
+  );
+}
+
+#[test]
+fn strings_can_start_mid_neck() {
+  use guitar::{string::String, Capo, Fretboard, Pitch};
+
+  let mut banjo = Fretboard::new_from_strings(
+    vec![
+      String::new(Pitch::new("G", 4), 18).with_start_fret(5),
+      String::new(Pitch::new("D", 3), 23),
+      String::new(Pitch::new("G", 3), 23),
+      String::new(Pitch::new("B", 3), 23),
+      String::new(Pitch::new("D", 4), 23),
+    ],
+    23,
+  );
+
+  assert_eq!(banjo.nut(0), 5);
+  assert_eq!(banjo.absolute_fret(0, 2), 7);
+  assert_eq!(banjo.relative_fret(0, 3), None);
+  assert_eq!(banjo.playable_frets(0), Some(0..=17));
+  assert_eq!(
+    banjo.positions_of(&"A4".parse().unwrap(), ..10),
+    [(0, 2), (4, 7)]
+  );
+
+  banjo.set_capo(Some(Capo::new(2))).unwrap();
+
+  assert_eq!(banjo.nut(0), 5);
+  assert_eq!(banjo.nut(1), 2);
+  assert_eq!(banjo.pitch_at(1, 0).unwrap().to_string(), "E3");
+
+  banjo.set_capo(Some(Capo::new(7))).unwrap();
+
+  assert_eq!(banjo.pitch_at(0, 0).unwrap().to_string(), "A4");
+  assert_eq!(banjo.strings_at(6), Vec::<usize>::new());
+}
 
 #[test]
-fn it_works() {
-  assert_eq!(4, 4);
+fn unfretted_strings_only_sound_open() {
+  use guitar::{string::String, Fretboard, Pitch};
+
+  let harp_guitar = Fretboard::new_from_strings(
+    vec![
+      String::new(Pitch::new("C", 2), 1),
+      String::new(Pitch::new("E", 2), 20),
+    ],
+    20,
+  );
+
+  assert_eq!(harp_guitar.playable_frets(0), Some(0..=0));
+  assert_eq!(harp_guitar.pitch_at(0, 1), None);
+  assert_eq!(harp_guitar.strings_at(0), [0, 1]);
+  assert_eq!(harp_guitar.strings_at(1), [1]);
+  assert_eq!(
+    harp_guitar
+      .positions_of_class(guitar::PitchClass::from_name("C").unwrap(), ..),
+    [(0, 0), (1, 8)]
+  );
 }
@@ -0,0 +1,74 @@
+use guitar::{string::String, Capo, Fretboard, Neck, Pitch, Tuning};
+
+#[test]
+fn positions_map_to_frequencies_and_back() {
+  let string = String::new(Pitch::new("E", 1), 1);
+
+  for position in [0., 0.5, 2.7, 7., 12., 23.9] {
+    let frequency = string.frequency_at_position(position);
+
+    assert!(
+      (string.position_of_frequency(frequency).unwrap() - position).abs()
+        < 1e-9
+    );
+    assert!(
+      (string
+        .position_of_pitch(&string.pitch_at_position(position))
+        .unwrap()
+        - position)
+        .abs()
+        < 1e-9
+    );
+  }
+
+  assert!(
+    (string.frequency_at_position(12.) - 2. * string.pitch().frequency()).abs()
+      < 1e-9
+  );
+  assert_eq!(string.position_of_frequency(40.), None);
+  assert_eq!(string.pitch_at_position(4.5).to_string(), "G#1 +50.0¢");
+}
+
+#[test]
+fn glissandi_slide_through_quarter_tones() {
+  let string = String::new(Pitch::new("A", 2), 13);
+  let slide = string
+    .glissando(0., 2., 5)
+    .iter()
+    .map(ToString::to_string)
+    .collect::<Vec<_>>();
+
+  assert_eq!(slide, ["A2", "A2 +50.0¢", "A#2", "A#2 +50.0¢", "B2"]);
+}
+
+#[test]
+fn distances_along_the_neck() {
+  let neck = Neck::new(864., 24);
+  let bass = Fretboard::new_from_tuning(&Tuning::named("Bass").unwrap(), 25);
+  let position = neck.position_at_distance(0, 300.).unwrap();
+  let pitch = bass.pitch_at_position(0, position).unwrap();
+
+  assert!(
+    (neck.distance_of_position(0, position).unwrap() - 300.).abs() < 1e-9
+  );
+  assert_eq!(pitch.to_string(), "B1 +38.4¢");
+  assert_eq!(neck.position_at_distance(0, 900.), None);
+  assert_eq!(neck.position_at_distance(6, 300.), None);
+}
+
+#[test]
+fn capos_shift_continuous_positions() {
+  let mut fretboard = Fretboard::new(13);
+
+  fretboard.set_capo(Some(Capo::new(2))).unwrap();
+
+  assert_eq!(
+    fretboard.pitch_at_position(0, 0.).unwrap().to_string(),
+    "F#2"
+  );
+  assert_eq!(fretboard.pitch_at_position(0, 10.5), None);
+  assert!(
+    (fretboard.position_of_frequency(0, 110.).unwrap() - 3.).abs() < 1e-9
+  );
+  assert_eq!(fretboard.position_of_frequency(0, 82.41), None);
+}
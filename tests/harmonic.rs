@@ -0,0 +1,48 @@
+use guitar::{string::String, temperament::JustIntonation, Pitch, PitchClass};
+
+#[test]
+fn natural_harmonics_of_low_e() {
+  let string = String::new(Pitch::new("E", 2), 25);
+  let harmonics = string.harmonics();
+  let nodes = harmonics
+    .iter()
+    .map(|harmonic| (harmonic.node() * 10.).round() / 10.)
+    .collect::<Vec<_>>();
+
+  assert_eq!(harmonics.len(), 15);
+  assert_eq!(harmonics[0].partial(), 2);
+  assert!(harmonics.iter().all(guitar::harmonic::Harmonic::is_natural));
+  assert_eq!(&nodes[..6], [12., 7., 5., 3.9, 3.2, 2.7]);
+  assert_eq!(harmonics[0].pitch().to_string(), "E3");
+  assert_eq!(harmonics[1].pitch().to_string(), "B3 +2.0¢");
+  assert_eq!(harmonics[3].pitch().to_string(), "G#4 -13.7¢");
+  assert_eq!(harmonics[14].pitch().to_string(), "E6");
+  assert!(harmonics[14].cents_from_equal_temperament().abs() < 1e-9);
+  assert_eq!(harmonics[4].nodes().len(), 2);
+  assert_eq!(harmonics[3].nodes().len(), 4);
+  assert!(string.harmonic(0).is_none());
+}
+
+#[test]
+fn artificial_harmonics_follow_the_fret() {
+  let string = String::new(Pitch::new("A", 2), 20);
+  let octave = string.artificial_harmonic(5, 2).unwrap();
+
+  assert_eq!(octave.fret(), 5);
+  assert!(!octave.is_natural());
+  assert!((octave.node() - 17.).abs() < 1e-9);
+  assert_eq!(octave.pitch().to_string(), "D4");
+  assert!(string.artificial_harmonic(20, 2).is_none());
+}
+
+#[test]
+fn harmonics_keep_the_temperament() {
+  let c = PitchClass::from_name("C").unwrap();
+  let string = String::new(
+    Pitch::new("C", 3).with_temperament(JustIntonation::new(c)),
+    13,
+  );
+
+  assert_eq!(string.harmonic(3).unwrap().pitch().to_string(), "G4");
+  assert_eq!(string.harmonic(5).unwrap().pitch().to_string(), "E5");
+}
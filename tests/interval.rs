@@ -0,0 +1,83 @@
+use guitar::{interval::Quality, Interval, IntervalError, Pitch};
+
+fn pitch(name: &str) -> Pitch { name.parse().unwrap() }
+
+#[test]
+fn parses_and_displays_intervals() {
+  for (name, semitones) in [
+    ("P1", 0),
+    ("m3", 3),
+    ("M3", 4),
+    ("A4", 6),
+    ("d5", 6),
+    ("P5", 7),
+    ("d7", 9),
+    ("P8", 12),
+    ("M9", 14),
+    ("AA4", 7),
+    ("P11", 17),
+  ] {
+    let interval = name.parse::<Interval>().unwrap();
+
+    assert_eq!(interval.semitones(), semitones, "{name}");
+    assert_eq!(interval.to_string(), name);
+  }
+
+  assert_eq!(
+    "P3".parse::<Interval>(),
+    Err(IntervalError::ImpossibleQuality(Quality::Perfect, 3))
+  );
+  assert!("M0".parse::<Interval>().is_err());
+  assert!("X5".parse::<Interval>().is_err());
+  assert_eq!(Interval::new(Quality::Diminished(0), 3), None);
+  assert_eq!(Interval::new(Quality::Augmented(0), 5), None);
+  assert_eq!(
+    Interval::new(Quality::Diminished(1), 3).map(|interval| interval.quality()),
+    Some(Quality::Diminished(1))
+  );
+}
+
+#[test]
+fn inverts_and_compounds() {
+  assert_eq!(
+    Interval::PERFECT_UNISON.inversion(),
+    Interval::PERFECT_OCTAVE
+  );
+  assert_eq!(Interval::MINOR_THIRD.inversion(), Interval::MAJOR_SIXTH);
+  assert_eq!(Interval::MAJOR_SECOND.compound(1), Interval::MAJOR_NINTH);
+  assert_eq!(Interval::MAJOR_NINTH.simple(), Interval::MAJOR_SECOND);
+  assert_eq!(Interval::PERFECT_OCTAVE.simple(), Interval::PERFECT_OCTAVE);
+  assert!(Interval::MAJOR_NINTH.is_compound());
+  assert!((Interval::PERFECT_FIFTH.cents() - 700.).abs() < f64::EPSILON);
+}
+
+#[test]
+fn pitch_arithmetic_keeps_spelling() {
+  assert_eq!(
+    (pitch("C4") + Interval::AUGMENTED_FOURTH).to_string(),
+    "F#4"
+  );
+  assert_eq!(
+    (pitch("C4") + Interval::DIMINISHED_FIFTH).to_string(),
+    "Gb4"
+  );
+  assert_eq!((pitch("B3") + Interval::MINOR_SECOND).to_string(), "C4");
+  assert_eq!((pitch("E4") + Interval::MAJOR_THIRD).to_string(), "G#4");
+  assert_eq!((pitch("Db4") + Interval::MAJOR_NINTH).to_string(), "Eb5");
+  assert_eq!((pitch("F4") - Interval::MAJOR_THIRD).to_string(), "Db4");
+  assert_eq!((pitch("C4") - Interval::MINOR_SECOND).to_string(), "B3");
+  assert_eq!(
+    (pitch("A4") + Interval::PERFECT_FIFTH).midi_note(),
+    pitch("E5").midi_note()
+  );
+}
+
+#[test]
+fn pitch_difference_is_spelled() {
+  assert_eq!(pitch("E4") - pitch("C4"), Interval::MAJOR_THIRD);
+  assert_eq!(pitch("C4") - pitch("E4"), Interval::MAJOR_THIRD);
+  assert_eq!(pitch("F#4") - pitch("C4"), Interval::AUGMENTED_FOURTH);
+  assert_eq!(pitch("Gb4") - pitch("C4"), Interval::DIMINISHED_FIFTH);
+  assert_eq!(pitch("D5") - pitch("C4"), Interval::MAJOR_NINTH);
+  assert_eq!((pitch("Cb4") - pitch("B#3")).to_string(), "dd2");
+}
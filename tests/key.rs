@@ -0,0 +1,77 @@
+use guitar::{scale::ScaleKind, Key, Pitch, PitchClass};
+
+fn class(name: &str) -> PitchClass { name.parse().unwrap() }
+
+fn names(classes: &[PitchClass]) -> Vec<String> {
+  classes.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn key_signatures() {
+  assert_eq!(Key::major(class("C")).signature(), 0);
+  assert_eq!(Key::major(class("E")).sharps(), 4);
+  assert_eq!(Key::major(class("Db")).flats(), 5);
+  assert_eq!(Key::minor(class("G")).flats(), 2);
+  assert_eq!(
+    Key::new(class("E"), ScaleKind::Dorian).unwrap().signature(),
+    2
+  );
+  assert_eq!(
+    Key::new(class("F"), ScaleKind::Lydian).unwrap().signature(),
+    0
+  );
+  assert!(Key::new(class("C"), ScaleKind::Blues).is_none());
+  assert_eq!(
+    names(&Key::major(class("A")).accidentals()),
+    ["F#", "C#", "G#"]
+  );
+  assert_eq!(
+    names(&Key::minor(class("F")).accidentals()),
+    ["Bb", "Eb", "Ab", "Db"]
+  );
+}
+
+#[test]
+fn related_keys() {
+  let e_flat = Key::major(class("Eb"));
+
+  assert_eq!(e_flat.relative(), Key::minor(class("C")));
+  assert_eq!(Key::minor(class("C")).relative(), e_flat);
+  assert_eq!(e_flat.parallel(), Key::minor(class("Eb")));
+  assert_eq!(e_flat.dominant(), Key::major(class("Bb")));
+  assert_eq!(e_flat.subdominant(), Key::major(class("Ab")));
+  assert_eq!(Key::major(class("C#")).dominant(), Key::major(class("Ab")));
+  assert_eq!(
+    Key::new(class("D"), ScaleKind::Dorian).unwrap().relative(),
+    Key::major(class("C"))
+  );
+}
+
+#[test]
+fn spells_in_context() {
+  let d_major = Key::major(class("D"));
+  let d_flat_major = Key::major(class("Db"));
+
+  assert_eq!(d_major.spell_class(class("Gb")).to_string(), "F#");
+  assert_eq!(d_flat_major.spell_class(class("F#")).to_string(), "Gb");
+  assert_eq!(
+    names(&d_flat_major.pitch_classes()),
+    ["Db", "Eb", "F", "Gb", "Ab", "Bb", "C"]
+  );
+  assert_eq!(
+    Key::major(class("Gb"))
+      .spell(&Pitch::new("B", 3))
+      .to_string(),
+    "Cb4"
+  );
+  assert_eq!(
+    guitar::convert::frequency_to_pitch_in_key(
+      466.16,
+      440.,
+      69,
+      Key::major(class("F"))
+    )
+    .to_string(),
+    "Bb4"
+  );
+}
@@ -0,0 +1,119 @@
+use guitar::{string::String, utility, Fretboard, FretboardError, Neck, Pitch};
+
+#[test]
+fn frets_halve_the_scale_length_every_octave() {
+  let neck = Neck::new(650., 25);
+  let positions = neck.fret_positions();
+
+  assert_eq!(positions.len(), 25);
+  assert_eq!(positions[0], 0.);
+  assert!((positions[12] - 325.).abs() < 1e-9);
+  assert!((positions[24] - 487.5).abs() < 1e-9);
+  assert!(neck.check_twelfth_fret(positions[12], 1e-9));
+
+  for fret in 1..25 {
+    assert!(
+      (neck.fret_spacing(fret) - (positions[fret] - positions[fret - 1])).abs()
+        < 1e-9
+    );
+    assert!(
+      (neck.sounding_length(fret) + positions[fret] - neck.scale_length())
+        .abs()
+        < 1e-9
+    );
+  }
+}
+
+#[test]
+fn converts_inches() {
+  let neck = Neck::new_from_inches(24.75, 22);
+
+  assert!(
+    (utility::millimeters_to_inches(neck.scale_length()) - 24.75).abs() < 1e-9
+  );
+  assert!(
+    (utility::millimeters_to_inches(neck.fret_position(12)) - 12.375).abs()
+      < 1e-9
+  );
+}
+
+#[test]
+fn follows_the_fretboard() {
+  let bass =
+    Fretboard::new_from_tuning(&guitar::Tuning::named("Bass").unwrap(), 21);
+  let mut neck = Neck::new_from_fretboard(864., &bass);
+
+  neck.set_string_spacing(30., 57.);
+
+  assert_eq!(neck.frets(), 21);
+  assert_eq!(neck.strings(), 4);
+  assert!((neck.string_spacing_at(432.) - 43.5).abs() < 1e-9);
+  assert!(neck
+    .string_offsets_at(864.)
+    .iter()
+    .zip([-28.5, -9.5, 9.5, 28.5])
+    .all(|(offset, expected)| (offset - expected).abs() < 1e-9));
+
+  let drone = String::new(Pitch::new("G", 4), 18).with_start_fret(5);
+  let positions = Neck::new(670., 22)
+    .string_fret_positions(0, &drone)
+    .unwrap();
+
+  assert_eq!(positions.len(), 18);
+  assert!((positions[0] - Neck::new(670., 22).fret_position(5)).abs() < 1e-9);
+}
+
+#[test]
+fn fans_frets_around_the_perpendicular_fret() {
+  let neck = Neck::new_multiscale(686., 648., 7, 25, 8);
+
+  assert_eq!(neck.strings(), 7);
+  assert_eq!(neck.perpendicular_fret(), 8);
+  assert!(!Neck::new(648., 25).is_multiscale());
+
+  for string in 0..7 {
+    let scale_length = neck.scale_lengths()[string];
+
+    assert!(
+      (neck.fret_position_on(string, 12).unwrap() - scale_length / 2.).abs()
+        < 1e-9
+    );
+  }
+
+  assert_eq!(neck.fret_position_on(7, 12), None);
+
+  let perpendicular = neck.fret_points(8);
+
+  assert!(perpendicular.iter().all(|(along, _)| along.abs() < 1e-9));
+  assert!(perpendicular.windows(2).all(|pair| pair[0].1 < pair[1].1));
+
+  let nut = neck.fret_points(0);
+
+  assert!(nut.windows(2).all(|pair| pair[0].0 < pair[1].0));
+  assert!(neck.fret_angle(0) > neck.fret_angle(12));
+  assert!(neck.fret_angle(8).abs() < 1e-9);
+  assert!(Neck::new(648., 25).fret_angle(5).abs() < 1e-9);
+}
+
+#[test]
+fn necks_match_the_fretboard() {
+  let mut fretboard = Fretboard::new(25);
+
+  assert_eq!(
+    fretboard.set_neck(Some(Neck::new_multiscale(686., 648., 7, 25, 8))),
+    Err(FretboardError::NeckStringCount {
+      neck: 7,
+      strings: 6
+    })
+  );
+  assert!(fretboard.neck().is_none());
+
+  fretboard
+    .set_neck(Some(Neck::new_multiscale(686., 648., 6, 25, 8)))
+    .unwrap();
+
+  assert!(fretboard
+    .with_tuning(&"B1 E2 A2 D3 G3 B3 E4".parse().unwrap())
+    .neck()
+    .is_none());
+}
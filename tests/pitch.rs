@@ -0,0 +1,202 @@
+use guitar::{Accidental, Letter, Pitch, PitchClass};
+
+#[test]
+fn spellings_round_trip() {
+  for name in [
+    "C", "C#", "Db", "Cb", "B#", "E#", "Fb", "Fbb", "Gx", "C##", "Abb",
+  ] {
+    let class = PitchClass::from_name(name).unwrap();
+    let rendered = class.to_string();
+
+    assert_eq!(PitchClass::from_name(&rendered), Some(class), "{name}");
+  }
+}
+
+#[test]
+fn pitch_keeps_spelling() {
+  let pitch = Pitch::new("Bb", 3);
+
+  assert_eq!(pitch.letter(), Letter::B);
+  assert_eq!(pitch.accidental(), Accidental::Flat);
+  assert_eq!(pitch.semitones(), Pitch::new("A#", 3).semitones());
+}
+
+#[test]
+fn octave_follows_letter() {
+  assert_eq!(
+    Pitch::new("Cb", 4).midi_note(),
+    Pitch::new("B", 3).midi_note()
+  );
+  assert_eq!(
+    Pitch::new("B#", 3).midi_note(),
+    Pitch::new("C", 4).midi_note()
+  );
+  assert_eq!(
+    Pitch::new("E#", 4).midi_note(),
+    Pitch::new("F", 4).midi_note()
+  );
+  assert_eq!(
+    Pitch::new("Fbb", 4).midi_note(),
+    Pitch::new("Eb", 4).midi_note()
+  );
+}
+
+#[test]
+fn enharmonics_do_not_panic() {
+  for class in PitchClass::from_name("C").unwrap().enharmonics() {
+    let pitch = Pitch::new_from_pitch_class(class, 4);
+
+    if let Some(enharmonic) = pitch.enharmonic() {
+      assert_eq!(enharmonic.midi_note(), pitch.midi_note());
+      assert!(enharmonic.pitch_class().is_enharmonic(class));
+    }
+  }
+
+  for letter in Letter::ALL {
+    for semitones in [-2, -1, 1, 2] {
+      let class =
+        PitchClass::new(letter, Accidental::from_semitones(semitones).unwrap());
+      let enharmonic = class.enharmonic().unwrap();
+
+      assert!(enharmonic.is_enharmonic(class));
+      assert_ne!(enharmonic, class);
+    }
+  }
+}
+
+#[test]
+fn parses_scientific_notation() {
+  for (input, display) in [
+    ("C#4", "C#4"),
+    ("Bb-1", "Bb-1"),
+    ("A♯4", "A#4"),
+    ("E♭3", "Eb3"),
+    ("bb2", "Bb2"),
+    ("Fx10", "F##10"),
+  ] {
+    assert_eq!(input.parse::<Pitch>().unwrap().to_string(), display);
+  }
+
+  assert_eq!(
+    "C#4".parse::<Pitch>().unwrap().midi_note(),
+    Pitch::new("C#", 4).midi_note()
+  );
+}
+
+#[test]
+fn parses_helmholtz_notation() {
+  for (input, display) in [
+    ("c'", "C4"),
+    ("c", "C3"),
+    ("C", "C2"),
+    ("C,", "C1"),
+    ("bb''", "Bb5"),
+    ("f#′", "F#4"),
+  ] {
+    let pitch = input.parse::<Pitch>().unwrap();
+
+    assert_eq!(pitch.to_string(), display);
+    assert_eq!(pitch.helmholtz().parse::<Pitch>().unwrap(), pitch);
+  }
+}
+
+#[test]
+fn rejects_malformed_pitches() {
+  assert_eq!("".parse::<Pitch>(), Err(guitar::PitchError::Empty));
+  assert_eq!(
+    "H4".parse::<Pitch>(),
+    Err(guitar::PitchError::UnknownLetter('H'))
+  );
+  assert!(matches!(
+    "C#b4".parse::<Pitch>(),
+    Err(guitar::PitchError::InvalidAccidental(_))
+  ));
+  assert!(matches!(
+    "C'".parse::<Pitch>(),
+    Err(guitar::PitchError::InvalidOctave(_))
+  ));
+}
+
+#[test]
+fn fallible_construction_reports_errors() {
+  use guitar::{Note, PitchError};
+
+  assert_eq!(Pitch::try_new("h", 4), Err(PitchError::UnknownLetter('h')));
+  assert_eq!(
+    Pitch::try_new("C?", 4),
+    Err(PitchError::InvalidAccidental("?".to_string()))
+  );
+  assert_eq!(
+    Pitch::try_new("C", -2),
+    Err(PitchError::OctaveOutOfRange(-2))
+  );
+  assert_eq!(
+    Pitch::try_from(0.),
+    Err(PitchError::NonPositiveFrequency(0.))
+  );
+  assert!(matches!(
+    Pitch::try_from(f64::INFINITY),
+    Err(PitchError::NonFiniteFrequency(_))
+  ));
+  assert!(Note::try_from("C#4").is_ok());
+  assert!(Note::try_from("C#42").is_err());
+  assert!(guitar::string::String::try_new("E2b", 12).is_err());
+  assert!(guitar::string::String::try_from("E10").is_err());
+  assert_eq!(
+    *guitar::string::String::try_from("E2").unwrap().fret_count(),
+    guitar::string::String::DEFAULT_FRET_COUNT
+  );
+  assert_eq!(Pitch::new("C", 10).octave(), 10);
+  assert_eq!(
+    Pitch::try_from("C10"),
+    Err(PitchError::OctaveOutOfRange(10))
+  );
+}
+
+#[test]
+fn keeps_cent_offsets() {
+  let mut pitch = Pitch::new_from_frequency(445.);
+
+  assert_eq!(pitch.to_string(), "A4 +19.6¢");
+  assert!((pitch.frequency() - 445.).abs() < 1e-9);
+  assert_eq!(pitch.midi_note(), 69);
+  assert_eq!(pitch.semitones(), 0);
+
+  pitch.set_octave(5);
+  assert!((pitch.frequency() - 890.).abs() < 1e-9);
+
+  pitch.set_semitones(3);
+  assert_eq!(pitch.to_string(), "C5 +19.6¢");
+
+  pitch.set_frequency(460.);
+  assert_eq!(pitch.to_string(), "A#4 -23.0¢");
+
+  let parsed = "Eb3 -31.5c".parse::<Pitch>().unwrap();
+
+  assert!((parsed.cents() - (-1800. - 31.5)).abs() < 1e-9);
+  assert_eq!(parsed.to_string(), "Eb3 -31.5¢");
+  assert_eq!(
+    "A4 sharp".parse::<Pitch>(),
+    Err(guitar::PitchError::InvalidCentOffset("sharp".to_string()))
+  );
+  assert_eq!(Pitch::new("A", 4).to_string(), "A4");
+}
+
+#[test]
+fn reference_keeps_spelling() {
+  let mut pitch = Pitch::new("Bb", 3);
+
+  pitch.set_base_frequency(442.);
+  assert_eq!(pitch.base_midi_note(), 69);
+  assert_eq!(pitch.to_string(), "Bb3");
+  assert!((pitch.frequency() - 442. * 2f64.powf(-11. / 12.)).abs() < 1e-9);
+
+  pitch.set_base_midi_note(60);
+  assert_eq!(pitch.reference().to_string(), "C4 = 442 Hz");
+  assert_eq!(pitch.semitones(), -2);
+  assert!((pitch.frequency() - 442. * 2f64.powf(-2. / 12.)).abs() < 1e-9);
+
+  pitch.set_semitones(7);
+  assert_eq!(pitch.to_string(), "G4");
+  assert_eq!(pitch.midi_note(), 67);
+}
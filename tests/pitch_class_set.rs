@@ -0,0 +1,85 @@
+use std::collections::HashSet;
+
+use guitar::{PitchClass, PitchClassSet};
+
+#[test]
+fn computes_normal_order_and_prime_form() {
+  let major = ["C", "E", "G"]
+    .map(|name| PitchClass::from_name(name).unwrap())
+    .into_iter()
+    .collect::<PitchClassSet>();
+
+  assert_eq!(major.normal_order(), [0, 4, 7]);
+  assert_eq!(major.prime_form(), [0, 3, 7]);
+  assert_eq!(
+    PitchClassSet::new(&[8, 2, 5, 11]).normal_order(),
+    [2, 5, 8, 11]
+  );
+  assert_eq!(
+    PitchClassSet::new(&[10, 1, 6, 7]).prime_form(),
+    [0, 1, 4, 7]
+  );
+  assert_eq!(PitchClassSet::new(&[]).prime_form(), Vec::<i64>::new());
+}
+
+#[test]
+fn transposes_inverts_and_complements() {
+  let set = PitchClassSet::new(&[0, 1, 4]);
+
+  assert_eq!(set.transpose(11).classes(), [0, 3, 11]);
+  assert_eq!(set.invert(0).classes(), [0, 8, 11]);
+  assert_eq!(set.invert(5).classes(), [1, 4, 5]);
+  assert!(set.is_equivalent(set.invert(7)));
+  assert_eq!(set.complement().len(), 9);
+  assert_eq!(set.complement().forte_number().to_string(), "9-3");
+  assert_eq!(set.to_string(), "{0, 1, 4}");
+}
+
+#[test]
+fn computes_interval_vectors_and_z_relations() {
+  let all_interval = PitchClassSet::new(&[0, 1, 4, 6]);
+  let partner = all_interval.z_related().unwrap();
+
+  assert_eq!(all_interval.interval_vector(), [1, 1, 1, 1, 1, 1]);
+  assert_eq!(partner.prime_form(), [0, 1, 3, 7]);
+  assert!(all_interval.is_z_related(partner));
+  assert_eq!(all_interval.forte_number().to_string(), "4-Z15");
+  assert_eq!(partner.forte_number().to_string(), "4-Z29");
+  assert_eq!(PitchClassSet::new(&[0, 3, 6, 9]).z_related(), None);
+  assert_eq!(
+    PitchClassSet::new(&[0, 1, 2, 3, 5, 6])
+      .forte_number()
+      .to_string(),
+    "6-Z3"
+  );
+
+  let seven = PitchClassSet::new(&[0, 1, 3, 4, 8]).complement();
+  let seven_partner = seven.z_related().unwrap();
+
+  assert!(seven.is_z_related(seven_partner));
+  assert_eq!(seven.forte_number().to_string(), "7-Z17");
+  assert_eq!(seven_partner.forte_number().to_string(), "7-Z37");
+}
+
+#[test]
+fn numbers_every_set_class() {
+  let classes = (0..1 << 12)
+    .map(|mask: i64| {
+      PitchClassSet::new(
+        &(0..12)
+          .filter(|class| mask & 1 << class != 0)
+          .collect::<Vec<_>>(),
+      )
+    })
+    .map(|set| PitchClassSet::new(&set.prime_form()))
+    .collect::<HashSet<_>>();
+  let numbers = classes
+    .iter()
+    .map(|set| set.forte_number())
+    .collect::<HashSet<_>>();
+
+  assert_eq!(classes.len(), 224);
+  assert_eq!(numbers.len(), 224);
+  assert!(numbers.iter().all(|number| number.ordinal() > 0));
+  assert_eq!(numbers.iter().filter(|number| number.is_z()).count(), 46);
+}
@@ -0,0 +1,165 @@
+use guitar::{
+  scala::{KeyboardMapping, ScalaDegree, ScalaScale, ScalaTuning},
+  string::String,
+  temperament::{Temperament, WellTemperament},
+  Pitch, PitchClass, Reference, ScalaError,
+};
+
+const JUST: &str = "! ji.scl
+!
+5-limit just intonation
+ 12
+!
+ 16/15
+ 9/8
+ 6/5
+ 5/4
+ 4/3
+ 45/32
+ 3/2
+ 8/5
+ 5/3
+ 9/5
+ 15/8
+ 2
+";
+
+#[test]
+fn parses_scales() {
+  let scale = JUST.parse::<ScalaScale>().unwrap();
+
+  assert_eq!(scale.description(), "5-limit just intonation");
+  assert_eq!(scale.len(), 12);
+  assert_eq!(scale.degrees()[11], ScalaDegree::Ratio(2, 1));
+  assert!((scale.period() - 1200.).abs() < 1e-9);
+  assert!((scale.cents(-5) - (scale.cents(7) - 1200.)).abs() < 1e-9);
+  assert_eq!(
+    "100.0 cents".parse::<ScalaDegree>(),
+    Ok(ScalaDegree::Cents(100.))
+  );
+}
+
+#[test]
+fn reports_invalid_scales() {
+  assert_eq!(
+    "Short\n3\n9/8\n5/4\n".parse::<ScalaScale>(),
+    Err(ScalaError::UnexpectedEnd)
+  );
+  assert_eq!(
+    "Bad\n1\n-3/2\n".parse::<ScalaScale>(),
+    Err(ScalaError::InvalidPitch("-3/2".to_string()))
+  );
+  assert_eq!(
+    "Bad\ntwelve\n".parse::<ScalaScale>(),
+    Err(ScalaError::InvalidNumber("twelve".to_string()))
+  );
+  assert_eq!(
+    "Empty\n0\n".parse::<ScalaScale>(),
+    Err(ScalaError::EmptyScale)
+  );
+}
+
+#[test]
+fn round_trips_files() {
+  let scale = JUST.parse::<ScalaScale>().unwrap();
+  let mapping = KeyboardMapping::new_from_keys(
+    vec![Some(0), None, Some(2), Some(4)],
+    62,
+    62,
+    293.66,
+    5,
+  );
+
+  assert_eq!(scale.to_string().parse::<ScalaScale>(), Ok(scale));
+  assert_eq!(mapping.to_string().parse::<KeyboardMapping>(), Ok(mapping));
+
+  let exported = ScalaScale::new_from_temperament(
+    "Kirnberger III",
+    &WellTemperament::KIRNBERGER_III,
+  );
+  let imported = exported.to_string().parse::<ScalaScale>().unwrap();
+  let tuning = ScalaTuning::new_from_scale(imported);
+
+  for name in ["C", "D", "F#", "A", "Bb"] {
+    let class = PitchClass::from_name(name).unwrap();
+
+    assert!(
+      (tuning.cents(class) - WellTemperament::KIRNBERGER_III.cents(class))
+        .abs()
+        < 1e-4
+    );
+  }
+}
+
+#[test]
+fn maps_keys_to_degrees() {
+  let mapping = "! whole tone on white keys
+7
+0
+127
+60
+69
+440.0
+6
+0
+x
+1
+x
+2
+3
+x
+"
+  .parse::<KeyboardMapping>()
+  .unwrap();
+  let scale = "Whole tone\n6\n200.\n400.\n600.\n800.\n1000.\n2/1\n"
+    .parse::<ScalaScale>()
+    .unwrap();
+  let tuning = ScalaTuning::new(scale, mapping);
+  let frequency = |midi_note| tuning.frequency_of(midi_note);
+  let sounding = |name| {
+    Pitch::new(name, 4)
+      .with_temperament(tuning.clone())
+      .frequency()
+  };
+
+  assert_eq!(tuning.mapping().size(), 7);
+  assert_eq!(frequency(61), None);
+  assert_eq!(frequency(66), None);
+  assert!((frequency(67).unwrap() / frequency(60).unwrap() - 2.).abs() < 1e-12);
+  assert!(
+    (frequency(64).unwrap() / frequency(62).unwrap() - 2f64.powf(1. / 6.))
+      .abs()
+      < 1e-12
+  );
+  // Unmapped keys sound as the nearest mapped key, preferring the lower one.
+  assert!((sounding("C#") - frequency(60).unwrap()).abs() < 1e-9);
+  assert!((sounding("F#") - frequency(65).unwrap()).abs() < 1e-9);
+}
+
+#[test]
+fn tunes_pitches_and_strings() {
+  let tuning = ScalaTuning::new_from_scale(JUST.parse().unwrap());
+  let string = String::new(Pitch::new("A", 2).with_temperament(tuning), 13);
+  let ratio = |fret| {
+    string.fret(fret).unwrap().frequency() / string.fret(0).unwrap().frequency()
+  };
+
+  assert!((string.fret(0).unwrap().frequency() - 110.).abs() < 1e-9);
+  assert!((ratio(12) - 2.).abs() < 1e-12);
+  // A is 5/3 above C, and the E above it is 5/2, a pure fifth higher.
+  assert!((ratio(7) - 1.5).abs() < 1e-12);
+
+  let mut a = Pitch::new("A", 2)
+    .with_temperament(ScalaTuning::new_from_scale(JUST.parse().unwrap()));
+
+  assert_eq!(
+    ScalaTuning::new_from_scale(JUST.parse().unwrap())
+      .mapping()
+      .reference(),
+    Reference::default()
+  );
+
+  a.set_reference(Reference::default().with_frequency(432.));
+
+  assert!((a.frequency() - 108.).abs() < 1e-9);
+}
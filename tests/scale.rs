@@ -0,0 +1,108 @@
+use guitar::{scale::ScaleKind, Pitch, Scale};
+
+fn spell(root: &str, kind: ScaleKind) -> Vec<String> {
+  Scale::new(root.parse().unwrap(), kind)
+    .pitch_classes()
+    .iter()
+    .map(ToString::to_string)
+    .collect()
+}
+
+#[test]
+fn spells_built_in_scales() {
+  assert_eq!(
+    spell("F4", ScaleKind::MAJOR),
+    ["F", "G", "A", "Bb", "C", "D", "E"]
+  );
+  assert_eq!(
+    spell("C#4", ScaleKind::MINOR),
+    ["C#", "D#", "E", "F#", "G#", "A", "B"]
+  );
+  assert_eq!(
+    spell("A4", ScaleKind::HarmonicMinor),
+    ["A", "B", "C", "D", "E", "F", "G#"]
+  );
+  assert_eq!(
+    spell("E4", ScaleKind::PhrygianDominant),
+    ["E", "F", "G#", "A", "B", "C", "D"]
+  );
+  assert_eq!(
+    spell("G4", ScaleKind::Altered),
+    ["G", "Ab", "Bb", "Cb", "Db", "Eb", "F"]
+  );
+  assert_eq!(
+    spell("A4", ScaleKind::Blues),
+    ["A", "C", "D", "Eb", "E", "G"]
+  );
+  assert_eq!(
+    spell("C4", ScaleKind::DiminishedWholeHalf),
+    ["C", "D", "Eb", "F", "Gb", "Ab", "Bbb", "B"]
+  );
+}
+
+#[test]
+fn scale_sizes() {
+  for (kind, length) in [
+    (ScaleKind::Lydian, 7),
+    (ScaleKind::Ultralocrian, 7),
+    (ScaleKind::MajorPentatonic, 5),
+    (ScaleKind::MajorBlues, 6),
+    (ScaleKind::BebopDominant, 8),
+    (ScaleKind::BebopMajor, 8),
+    (ScaleKind::WholeTone, 6),
+    (ScaleKind::DiminishedHalfWhole, 8),
+    (ScaleKind::Chromatic, 12),
+  ] {
+    let scale = Scale::new(Pitch::new("C", 4), kind);
+
+    assert_eq!(scale.len(), length, "{kind}");
+    assert_eq!(scale.degree(length + 1).unwrap().to_string(), "C5");
+  }
+}
+
+#[test]
+fn modes_rotate_the_parent_scale() {
+  let a_melodic = Scale::new(Pitch::new("A", 3), ScaleKind::MelodicMinor);
+
+  assert_eq!(
+    a_melodic.mode(4),
+    Some(Scale::new(Pitch::new("D", 4), ScaleKind::LydianDominant))
+  );
+  assert_eq!(
+    a_melodic.mode(7),
+    Some(Scale::new(Pitch::new("G#", 4), ScaleKind::Altered))
+  );
+  assert!(a_melodic.mode(8).is_none());
+}
+
+#[test]
+fn iterates_across_octaves() {
+  let scale = Scale::new(Pitch::new("E", 2), ScaleKind::MinorPentatonic);
+  let pitches = scale.pitches().take(11).collect::<Vec<_>>();
+
+  assert_eq!(pitches[5].to_string(), "E3");
+  assert_eq!(pitches[10].to_string(), "E4");
+  assert!(pitches
+    .windows(2)
+    .all(|pair| pair[0].frequency() < pair[1].frequency()));
+  assert_eq!(scale.degree_of("G".parse().unwrap()), Some(2));
+  assert!(!scale.contains("F".parse().unwrap()));
+}
+
+#[test]
+fn compound_intervals_fold_into_one_octave() {
+  use guitar::Interval;
+
+  let scale = Scale::new_from_intervals(
+    Pitch::new("C", 4),
+    vec![
+      Interval::MAJOR_NINTH,
+      Interval::MAJOR_THIRD,
+      Interval::PERFECT_OCTAVE,
+    ],
+  );
+
+  assert_eq!(scale.len(), 3);
+  assert_eq!(scale.degree(4).unwrap().to_string(), "C5");
+  assert_eq!(scale.degree(5).unwrap().to_string(), "D5");
+}
@@ -0,0 +1,86 @@
+use guitar::{
+  string_set::{Construction, StringSet, TensionProfile},
+  tension::Material,
+  Neck, Tuning,
+};
+
+#[test]
+fn profiles_shape_the_targets() {
+  let even = TensionProfile::Even(18.);
+  let balanced = TensionProfile::Balanced {
+    first: 20.,
+    last: 15.,
+  };
+  let heavier_bass = TensionProfile::HeavierBass(16.);
+
+  assert_eq!(even.target(0, 6), even.target(5, 6));
+  assert!((balanced.target(0, 6).pounds() - 20.).abs() < 1e-9);
+  assert!((balanced.target(5, 6).pounds() - 15.).abs() < 1e-9);
+  assert!((heavier_bass.target(0, 6).pounds() - 20.).abs() < 1e-9);
+  assert!(heavier_bass.target(2, 6) > heavier_bass.target(3, 6));
+}
+
+#[test]
+fn ranks_gauges_by_tension() {
+  let set = StringSet::design(
+    &Tuning::named("Baritone").unwrap(),
+    &Neck::new_from_inches(27., 24),
+    TensionProfile::HeavierBass(16.),
+    Construction::Acoustic,
+  );
+
+  assert_eq!(set.recommendations().len(), 6);
+
+  for recommendation in set.recommendations() {
+    let candidates = recommendation.candidates();
+    let error = |index: usize| {
+      (candidates[index].1.pounds() - recommendation.target().pounds()).abs()
+    };
+
+    assert_eq!(candidates.len(), 5);
+    assert!((1..candidates.len()).all(|index| error(index - 1) <= error(index)));
+    assert!(candidates.iter().all(|(gauge, _)| matches!(
+      gauge.material(),
+      Material::PlainSteel | Material::PhosphorBronze
+    )));
+  }
+
+  assert!(set
+    .gauges()
+    .windows(2)
+    .all(|pair| pair[0].unwrap().diameter() > pair[1].unwrap().diameter()));
+}
+
+#[test]
+fn flags_unsafe_strings() {
+  let classical = |profile| {
+    StringSet::design(
+      &Tuning::default(),
+      &Neck::new(650., 19),
+      profile,
+      Construction::Classical,
+    )
+  };
+  let even = classical(TensionProfile::Even(15.));
+
+  assert!(even.flagged().is_empty());
+  assert_eq!(
+    even.gauges()[0].unwrap().material(),
+    Material::SilverWoundNylon
+  );
+  assert_eq!(even.gauges()[5].unwrap().material(), Material::Nylon);
+  assert_eq!(
+    classical(TensionProfile::Even(8.)).flagged(),
+    [0, 1, 2, 3, 4, 5]
+  );
+
+  let empty = StringSet::design_from_catalogue(
+    &Tuning::default(),
+    &Neck::new(650., 19),
+    TensionProfile::Even(8.),
+    &[],
+  );
+
+  assert_eq!(empty.gauges(), [None; 6]);
+  assert_eq!(empty.flagged().len(), 6);
+}
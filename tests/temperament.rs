@@ -0,0 +1,119 @@
+use guitar::{
+  string::String,
+  temperament::{
+    EqualTemperament, JustIntonation, Meantone, Pythagorean, Temperament,
+    WellTemperament,
+  },
+  Pitch, PitchClass,
+};
+
+fn class(name: &str) -> PitchClass { PitchClass::from_name(name).unwrap() }
+
+fn cents<T: Temperament>(temperament: &T, names: &[&str]) -> Vec<f64> {
+  names
+    .iter()
+    .map(|name| (temperament.cents(class(name)) * 1000.).round() / 1000.)
+    .collect()
+}
+
+#[test]
+fn twelve_equal_divisions_match_the_default() {
+  for name in ["C", "Eb", "F#", "A", "Cb", "B#"] {
+    let pitch = Pitch::new(name, 3);
+    let tempered = pitch.clone().with_temperament(EqualTemperament::new(12));
+
+    assert!((tempered.frequency() - pitch.frequency()).abs() < 1e-9);
+  }
+}
+
+#[test]
+#[should_panic = "an octave needs at least one division"]
+fn equal_temperaments_need_a_division() { let _ = EqualTemperament::new(0); }
+
+#[test]
+fn spells_regular_temperaments_on_the_line_of_fifths() {
+  assert_eq!(
+    cents(&Pythagorean, &["G", "C#", "Db", "B#"]),
+    [701.955, 113.685, 90.225, 1223.46]
+  );
+  assert_eq!(
+    cents(&Meantone::QUARTER_COMMA, &["E", "G#", "Ab"]),
+    [386.314, 772.627, 813.686]
+  );
+  assert_eq!(
+    cents(&EqualTemperament::new(31), &["C#", "Db"]),
+    [77.419, 116.129]
+  );
+}
+
+#[test]
+fn tunes_well_temperaments() {
+  let names = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+  ];
+
+  assert_eq!(
+    cents(&WellTemperament::WERCKMEISTER_III, &names),
+    [
+      0., 90.225, 192.18, 294.135, 390.225, 498.045, 588.27, 696.09, 792.18,
+      888.27, 996.09, 1092.18
+    ]
+  );
+  assert_eq!(
+    cents(&WellTemperament::KIRNBERGER_III, &names),
+    [
+      0., 90.225, 193.157, 294.135, 386.314, 498.045, 590.224, 696.578, 792.18,
+      889.735, 996.09, 1088.269
+    ]
+  );
+  assert_eq!(
+    cents(&WellTemperament::VALLOTTI, &["G", "F", "B", "F#"]),
+    [698.045, 501.955, 1090.225, 592.18]
+  );
+  assert_eq!(
+    cents(&WellTemperament::YOUNG, &["G", "F", "B", "F#"]),
+    [698.045, 498.045, 1090.225, 588.27]
+  );
+}
+
+#[test]
+fn tunes_just_intonation_above_a_tonic() {
+  let just = JustIntonation::new(class("D"));
+  let d = Pitch::new("D", 4).with_temperament(just);
+  let ratio = |name, octave| {
+    Pitch::new(name, octave).with_temperament(just).frequency() / d.frequency()
+  };
+
+  assert!((ratio("F#", 4) - 1.25).abs() < 1e-12);
+  assert!((ratio("A", 4) - 1.5).abs() < 1e-12);
+  assert!((ratio("C", 5) - 1.8).abs() < 1e-12);
+  assert!((ratio("D", 5) - 2.).abs() < 1e-12);
+}
+
+#[test]
+fn keeps_offsets_relative_to_the_temperament() {
+  let mut pitch = Pitch::new("E", 4).with_temperament(Pythagorean);
+
+  assert_eq!(pitch.to_string(), "E4");
+
+  pitch.set_frequency(330.);
+  assert_eq!(pitch.to_string(), "E4");
+  assert!((pitch.frequency() - 330.).abs() < 1e-9);
+
+  pitch.set_semitones(-2);
+  assert_eq!(pitch.to_string(), "G4");
+  assert!((pitch.frequency() - 440. / 9. * 8.).abs() < 1e-9);
+}
+
+#[test]
+fn frets_strings_in_the_temperament() {
+  let string = String::new(
+    Pitch::new("E", 2).with_temperament(WellTemperament::WERCKMEISTER_III),
+    13,
+  );
+  let frequency = |fret| string.fret(fret).unwrap().frequency();
+
+  assert_eq!(string.fret(4).unwrap().to_string(), "G#2");
+  assert!((frequency(12) / frequency(0) - 2.).abs() < 1e-12);
+  assert!((frequency(7) / frequency(0) - 1.5).abs() < 1e-12);
+}
@@ -0,0 +1,108 @@
+use guitar::{
+  tension::{Gauge, Material, Tension, TensionRating, GAUGES},
+  Fretboard, Interval, Neck, Transpose, Tuning,
+};
+
+fn gauge(material: Material, diameter: f64) -> Gauge {
+  Gauge::find(material, diameter).unwrap()
+}
+
+#[test]
+fn matches_published_tensions() {
+  let scale_length = Neck::new_from_inches(25.5, 22).scale_length();
+  let low_e = gauge(Material::NickelWound, 0.046).tension(82.41, scale_length);
+
+  assert!((low_e.pounds() - 17.5).abs() < 0.1);
+  assert!((low_e.kilograms() - 7.94).abs() < 0.05);
+  assert!((low_e.newtons() - 77.9).abs() < 0.5);
+  assert_eq!(low_e.to_string(), "17.5 lb");
+}
+
+#[test]
+fn ships_every_material() {
+  for material in [
+    Material::PlainSteel,
+    Material::NickelWound,
+    Material::PhosphorBronze,
+    Material::Nylon,
+    Material::SilverWoundNylon,
+    Material::Flatwound,
+  ] {
+    let gauges = GAUGES
+      .iter()
+      .filter(|gauge| gauge.material() == material)
+      .collect::<Vec<_>>();
+
+    assert!(gauges.len() > 10);
+    assert!(gauges
+      .windows(2)
+      .all(|pair| pair[0].diameter() < pair[1].diameter()
+        && pair[0].unit_weight() < pair[1].unit_weight()));
+  }
+
+  assert_eq!(
+    gauge(Material::PlainSteel, 0.0085).to_string(),
+    ".0085 plain steel"
+  );
+  assert!(Gauge::find(Material::Nylon, 0.010).is_none());
+}
+
+#[test]
+fn retuning_changes_the_rating() {
+  let neck = Neck::new_from_inches(25.5, 22);
+  let gauges = [
+    gauge(Material::PhosphorBronze, 0.053),
+    gauge(Material::PhosphorBronze, 0.042),
+    gauge(Material::PhosphorBronze, 0.032),
+    gauge(Material::PhosphorBronze, 0.024),
+    gauge(Material::PlainSteel, 0.016),
+    gauge(Material::PlainSteel, 0.012),
+  ];
+  let standard = Tension::of_fretboard(&Fretboard::new(22), &neck, &gauges);
+  let drop_b_flat = Tension::of_fretboard(
+    &Fretboard::new_from_tuning(
+      &Tuning::named("Drop C")
+        .unwrap()
+        .transpose_down(Interval::MAJOR_SECOND),
+      22,
+    ),
+    &neck,
+    &gauges,
+  );
+
+  assert!(standard
+    .iter()
+    .zip(gauges)
+    .all(|(tension, gauge)| tension.rating(gauge.material())
+      == TensionRating::Comfortable));
+  assert!(drop_b_flat[0] < standard[0]);
+  assert_eq!(
+    drop_b_flat[0].rating(Material::PhosphorBronze),
+    TensionRating::Floppy
+  );
+  assert_eq!(
+    Tension::new_from_pounds(40.).rating(Material::PlainSteel),
+    TensionRating::Dangerous
+  );
+}
+
+#[test]
+fn classical_sets_are_comfortable() {
+  let gauges = [
+    gauge(Material::SilverWoundNylon, 0.044),
+    gauge(Material::SilverWoundNylon, 0.036),
+    gauge(Material::SilverWoundNylon, 0.03),
+    gauge(Material::Nylon, 0.04),
+    gauge(Material::Nylon, 0.032),
+    gauge(Material::Nylon, 0.028),
+  ];
+  let tensions =
+    Tension::of_fretboard(&Fretboard::new(19), &Neck::new(650., 19), &gauges);
+
+  assert!((tensions[5].pounds() - 15.7).abs() < 0.1);
+  assert!(tensions
+    .iter()
+    .zip(gauges)
+    .all(|(tension, gauge)| tension.rating(gauge.material())
+      == TensionRating::Comfortable));
+}
@@ -0,0 +1,74 @@
+use guitar::{
+  transpose::Transposition, Chord, Fretboard, Interval, Key, Note, Pitch,
+  PitchClass, Transpose,
+};
+
+fn key(tonic: &str) -> Key { Key::major(PitchClass::from_name(tonic).unwrap()) }
+
+#[test]
+fn intervals_keep_spelling() {
+  let b_flat = "Bb3".parse::<Pitch>().unwrap();
+
+  assert_eq!(b_flat.transpose(Interval::MAJOR_SECOND).to_string(), "C4");
+  assert_eq!(b_flat.transpose(Interval::MINOR_THIRD).to_string(), "Db4");
+  assert_eq!(
+    b_flat.transpose_down(Interval::MAJOR_THIRD).to_string(),
+    "Gb3"
+  );
+  assert_eq!(
+    Note::new("C", 4)
+      .transpose(Interval::MINOR_THIRD)
+      .pitch()
+      .to_string(),
+    "Eb4"
+  );
+}
+
+#[test]
+fn semitones_are_spelled_in_a_key() {
+  let c = "C4".parse::<Pitch>().unwrap();
+
+  assert_eq!(c.transpose_in_key(1, key("Bb")).to_string(), "Db4");
+  assert_eq!(c.transpose_in_key(1, key("A")).to_string(), "C#4");
+  assert_eq!(c.transpose_in_key(-1, key("C")).to_string(), "B3");
+  assert_eq!(c.transpose_in_key(13, key("Ab")).to_string(), "Db5");
+  assert_eq!(
+    Transposition::InKey(6, key("Db"))
+      .pitch_class(PitchClass::from_name("C").unwrap()),
+    PitchClass::from_name("Gb").unwrap()
+  );
+}
+
+#[test]
+fn chords_keep_their_quality() {
+  let chord = "Cmaj7/E".parse::<Chord>().unwrap();
+  let transposed = chord.transpose(Interval::MAJOR_THIRD);
+
+  assert_eq!(transposed.to_string(), "Emaj7/G#");
+  assert_eq!(transposed.intervals(), chord.intervals());
+  assert_eq!(
+    chord.transpose_in_key(-4, key("Ab")).to_string(),
+    "Abmaj7/C"
+  );
+}
+
+#[test]
+fn fretboards_retune_every_string() {
+  let fretboard = Fretboard::new(13);
+  let drop = fretboard.transpose_down(Interval::MAJOR_SECOND);
+  let open = drop
+    .strings()
+    .iter()
+    .map(|string| string.pitch().to_string())
+    .collect::<Vec<_>>();
+
+  assert_eq!(open, ["D2", "G2", "C3", "F3", "A3", "D4"]);
+
+  for (before, after) in fretboard.strings().iter().zip(drop.strings()) {
+    for (fret, transposed) in before.frets().iter().zip(after.frets()) {
+      assert_eq!(fret.semitones() - 2, transposed.semitones());
+    }
+  }
+
+  assert_eq!(drop.reference(), fretboard.reference());
+}
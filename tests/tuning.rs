@@ -0,0 +1,49 @@
+use guitar::{Fretboard, Interval, Transpose, Tuning, TuningError};
+
+fn tuning(s: &str) -> Tuning { s.parse().unwrap() }
+
+#[test]
+fn parses_pitches_and_pitch_classes() {
+  assert_eq!(tuning("E2 A2 D3 G3 B3 E4"), Tuning::default());
+  assert_eq!(tuning("E A d g b e'"), Tuning::default());
+  assert_eq!(tuning("EADGBE"), Tuning::default());
+  assert_eq!(tuning("DbGbBbEb").to_string(), "Db2 Gb2 Bb2 Eb3");
+  assert_eq!(tuning("CGCFAD"), Tuning::named("Drop C").unwrap());
+  assert_eq!(tuning("BEADGBE").to_string(), "B1 E2 A2 D3 G3 B3 E4");
+  assert_eq!(tuning("GCEA").to_string(), "G4 C4 E4 A4");
+  assert_eq!(tuning("EAEAC#E").to_string(), "E2 A2 E3 A3 C#4 E4");
+  assert_eq!(tuning("G4 C4 E4 A4").pitches()[0].to_string(), "G4");
+  assert_eq!("".parse::<Tuning>(), Err(TuningError::Empty));
+  assert!(matches!(
+    "E2 H2".parse::<Tuning>(),
+    Err(TuningError::InvalidPitch(_))
+  ));
+}
+
+#[test]
+fn looks_up_presets_by_name() {
+  assert_eq!(tuning("open g").to_string(), "D2 G2 D3 G3 B3 D4");
+  assert_eq!(Tuning::named("LOW-G UKULELE").unwrap().len(), 4);
+  assert!(Tuning::named("Open H").is_none());
+  assert!(Tuning::presets().count() >= 25);
+  assert!(Tuning::presets().all(|(_, tuning)| !tuning.is_empty()));
+}
+
+#[test]
+fn fretboards_take_a_tuning() {
+  let drop_d = Tuning::named("Drop D").unwrap();
+  let fretboard = Fretboard::new(13).with_tuning(&drop_d);
+
+  assert_eq!(fretboard.tuning(), drop_d);
+  assert_eq!(fretboard.strings()[0].fret(2).unwrap().to_string(), "E2");
+  assert_eq!(
+    Fretboard::new_from_tuning(&Tuning::named("Seven-String").unwrap(), 25)
+      .strings()
+      .len(),
+    7
+  );
+  assert_eq!(
+    drop_d.transpose_down(Interval::MAJOR_SECOND),
+    Tuning::named("Drop C").unwrap()
+  );
+}